@@ -20,14 +20,12 @@ fn minify_wgsl(source: impl AsRef<Path>, target: impl AsRef<Path>, include_base:
     // Parse into NAGA module
     let mut module = naga::front::wgsl::parse_str(&source).expect("Error compiling WGSL shader");
 
+    naga::compact::compact(&mut module, naga::compact::KeepUnused::No);
     // Create the validator
     let info = Validator::new(ValidationFlags::all(), Capabilities::all())
         .validate(&module)
         .expect("Error while validating WGSL shader");
 
-    // Optimize shader, removing unused stuff
-    naga::compact::compact(&mut module);
-
     // Compile back into WGSL
     let output = naga::back::wgsl::write_string(&module, &info, WriterFlags::empty())
         .expect("Error converting WGSL module back to WGSL code");
@@ -1,11 +1,16 @@
 //! Show how a sound file can be played.
 //!
-//! To play it press the left mouse button on the canvas.
+//! To play it press the left mouse button on the canvas, it's panned and faded by the position of the mouse.
+//! To start looping it press the right mouse button, press it again to fade it out.
+//! Hold space to add an echo to the sound effects track.
 
-use chuot::{Config, Context, Game, MouseButton};
+use chuot::{Config, Context, Game, KeyCode, MouseButton, context::audio::SoundHandle};
 
-/// Define empty game state.
-struct GameState;
+/// Define a game state for our example.
+struct GameState {
+    /// Sound being looped.
+    looping: Option<SoundHandle>,
+}
 
 impl Game for GameState {
     /// Handle input events to play a sound.
@@ -13,9 +18,35 @@ impl Game for GameState {
         // Play a sound when the mouse is pressed
         if ctx.mouse_released(MouseButton::Left) {
             // Load the asset if not loaded yet
-            ctx.audio("switch31")
-                // Play the loaded sound
-                .play();
+            let audio = ctx
+                .audio("switch31")
+                // Route it through the sound effects track
+                .on_track("sfx");
+
+            // Pan and fade the sound by where it's clicked relative to the center of the screen
+            if let Some((x, y)) = ctx.main_camera().mouse() {
+                audio.at(x, y).play();
+            } else {
+                audio.play();
+            }
+        }
+
+        // Toggle looping the sound
+        if ctx.mouse_pressed(MouseButton::Right) {
+            if let Some(looping) = self.looping.take() {
+                // Fade out the sound in half a second
+                ctx.sound(looping).stop(0.5);
+            } else {
+                // Keep the handle so the sound can be stopped later
+                self.looping = Some(ctx.audio("switch31").with_loop().play());
+            }
+        }
+
+        // Fade the echo in and out
+        if ctx.key_pressed(KeyCode::Space) {
+            ctx.track("sfx").set_delay(0.5, 0.2);
+        } else if ctx.key_released(KeyCode::Space) {
+            ctx.track("sfx").set_delay(0.0, 0.2);
         }
     }
 
@@ -26,7 +57,7 @@ impl Game for GameState {
 /// Run the game.
 fn main() {
     // Start the game with defaults for the window
-    GameState.run(
+    GameState { looping: None }.run(
         chuot::load_assets!(),
         Config::default()
             .with_buffer_size((720.0, 576.0))
@@ -0,0 +1,105 @@
+//! Show how to synchronize sounds and animations to a musical beat.
+//!
+//! A metronome clicks on every beat with a higher click on every bar, while the bunny pulses to the beat.
+//! Press the left mouse button to speed up the tempo and the right mouse button to slow it down.
+//!
+//! The `bunnymark.png` sprite image for this example is:
+//! {{ img(src="/assets/bunnymark.png" alt="Sprite") }}
+//! With the following `bunnymark.ron` RON configuration file for positioning the center of the sprite:
+//! ```ron
+//! (pivot_x: Center, pivot_y: Center)
+//! ```
+//!
+//! The `pickup.ron` sound effect file used as the metronome click for this example is:
+//! ```ron
+//! (
+//!     waveform: Square,
+//!     sustain: 0.06,
+//!     punch: 0.4,
+//!     decay: 0.2,
+//!     frequency: 880.0,
+//!     arpeggio_multiplier: 1.5,
+//!     arpeggio_time: 0.06,
+//!     duty: 0.25,
+//!     volume: 0.3,
+//! )
+//! ```
+
+use chuot::{Config, Context, Game, MouseButton};
+
+/// Name of the clock.
+const CLOCK: &str = "metronome";
+/// Amount of beats in a bar.
+const BEATS_PER_BAR: u32 = 4;
+
+/// Define a game state for our example.
+struct GameState {
+    /// Tempo in beats per minute.
+    bpm: f32,
+}
+
+impl Game for GameState {
+    /// Schedule the clicks of the metronome.
+    fn update(&mut self, ctx: Context) {
+        // Change the tempo
+        if ctx.mouse_pressed(MouseButton::Left) {
+            self.bpm += 20.0;
+            ctx.clock(CLOCK).set_bpm(self.bpm, 0.0);
+        }
+        if ctx.mouse_pressed(MouseButton::Right) {
+            self.bpm = (self.bpm - 20.0).max(20.0);
+            ctx.clock(CLOCK).set_bpm(self.bpm, 0.0);
+        }
+
+        // Schedule the click of the next beat, so it plays exactly on time independent of the frame rate
+        let clock = ctx.clock(CLOCK);
+        if clock.is_new_beat() {
+            let pitch = if (clock.beat_in_bar() + 1) % BEATS_PER_BAR == 0 {
+                1.5
+            } else {
+                1.0
+            };
+
+            ctx.audio("pickup")
+                .with_pitch(pitch..=pitch)
+                .on_next_beat(CLOCK)
+                .play();
+        }
+    }
+
+    /// Pulse the bunny to the beat.
+    fn render(&mut self, ctx: Context) {
+        let scale = (1.0 - ctx.clock(CLOCK).beat_fraction()).mul_add(2.0, 1.0);
+
+        ctx.sprite("bunnymark").scale((scale, scale)).draw();
+
+        ctx.text(
+            "Beachball",
+            &format!("BPM: {}\nBar: {}", self.bpm, ctx.clock(CLOCK).bar() + 1),
+        )
+        .use_ui_camera()
+        .translate((2.0, 2.0))
+        .draw();
+    }
+
+    /// Start the clock.
+    fn init(&mut self, ctx: Context) {
+        ctx.clock(CLOCK).start(self.bpm, BEATS_PER_BAR);
+    }
+}
+
+/// Open an empty window.
+fn main() {
+    // Game configuration
+    let config = Config {
+        buffer_width: 240.0,
+        buffer_height: 192.0,
+        // Apply a minimum of 3 times scaling for the buffer
+        // Will result in a minimum, and on web exact, window size of 720x576
+        scaling: 3.0,
+        ..Default::default()
+    };
+
+    // Spawn the window and run the 'game'
+    GameState { bpm: 120.0 }.run(chuot::load_assets!(), config);
+}
@@ -20,6 +20,8 @@ use chuot::{Config, Context, Game, KeyCode, MouseButton};
 
 /// How fast the "player" moves.
 const PLAYER_SPEED: f32 = 90.0;
+/// How fast the camera rotates in radians per second.
+const ROTATION_SPEED: f32 = 1.0;
 
 /// Define a game state for our example.
 #[derive(Default)]
@@ -32,6 +34,8 @@ struct GameState {
     previous_player_x: f32,
     /// Previous vertical position, for smooth rendering.
     previous_player_y: f32,
+    /// Rotation of the main camera.
+    camera_rotation: f32,
 }
 
 impl Game for GameState {
@@ -61,6 +65,26 @@ impl Game for GameState {
         // Follow the player with the main camera
         ctx.main_camera().follow((self.player_x, self.player_y));
 
+        // Rotate the camera with the 'q' and 'e' keys
+        if ctx.key_held(KeyCode::KeyQ) {
+            self.camera_rotation -= ROTATION_SPEED * ctx.delta_time();
+        }
+        if ctx.key_held(KeyCode::KeyE) {
+            self.camera_rotation += ROTATION_SPEED * ctx.delta_time();
+        }
+        ctx.main_camera()
+            .set_rotation_smoothed(self.camera_rotation);
+
+        // Zoom the camera smoothly in and out with the 'z' and 'x' keys
+        if ctx.key_pressed(KeyCode::KeyZ) {
+            ctx.main_camera()
+                .set_zoom_smoothed(ctx.main_camera().zoom() * 2.0);
+        }
+        if ctx.key_pressed(KeyCode::KeyX) {
+            ctx.main_camera()
+                .set_zoom_smoothed(ctx.main_camera().zoom() / 2.0);
+        }
+
         // Shake the camera when clicking, using the mouse position to determine the intensity and duration
         if let Some((mouse_x, mouse_y)) = ctx.mouse() {
             if ctx.mouse_pressed(MouseButton::Left) {
@@ -110,15 +134,28 @@ impl Game for GameState {
         // Draw the instructions
         let instructions = if let Some((mouse_x, mouse_y)) = ctx.mouse() {
             format!(
-                "Arrow keys to move\n\nClick to shake camera:\n- Duration:  1.00 s\n- Amplitude: {:.2} px\n- Frequency: {:.2} hz",
+                "Arrow keys to move\nQ/E to rotate, Z/X to zoom\n\nClick to shake camera:\n- Duration:  1.00 s\n- Amplitude: {:.2} px\n- Frequency: {:.2} hz",
                 mouse_x / 10.0,
                 mouse_y / 4.0,
             )
         } else {
-            "Arrow keys to move\n\nClick to shake camera".to_owned()
+            "Arrow keys to move\nQ/E to rotate, Z/X to zoom\n\nClick to shake camera".to_owned()
         };
 
         ctx.text("Beachball", &instructions).use_ui_camera().draw();
+
+        // Draw how many sprites are drawn and how many are skipped because they are off-screen
+        ctx.text(
+            "Beachball",
+            &format!(
+                "Drawn: {}, culled: {}",
+                ctx.drawn_instances(),
+                ctx.culled_instances()
+            ),
+        )
+        .translate_y(272.0)
+        .use_ui_camera()
+        .draw();
     }
 
     /// Setup the camera.
@@ -127,6 +164,13 @@ impl Game for GameState {
         ctx.main_camera().set_lerp_x(0.1);
         // Follow the camera quickly vertically
         ctx.main_camera().set_lerp_y(0.5);
+        // Allow the player to move a bit without moving the camera
+        ctx.main_camera().set_deadzone(32.0, 32.0);
+        // Look ahead in the direction the player is moving
+        ctx.main_camera().set_look_ahead(0.5, 48.0);
+        // Don't show the area outside of the bunnies
+        ctx.main_camera()
+            .set_bounds((-1550.0, -1550.0), (1400.0, 1400.0));
     }
 }
 
@@ -0,0 +1,104 @@
+//! Show how to replace the OS cursor with a sprite and how to grab the cursor for mouse-look.
+//!
+//! Click to lock the cursor and move the bunny with the mouse movement, press escape to release it.
+//!
+//! The `cursor.png` sprite image for this example is:
+//! {{ img(src="/assets/cursor.png" alt="Cursor") }}
+//!
+//! The `bunnymark.png` sprite image for this example is:
+//! {{ img(src="/assets/bunnymark.png" alt="Sprite") }}
+//! With the following `bunnymark.ron` RON configuration file for positioning the center of the sprite:
+//! ```ron
+//! (pivot_x: Center, pivot_y: Center)
+//! ```
+
+use chuot::{Config, Context, CursorGrabMode, Game, KeyCode, MouseButton};
+
+/// How fast the bunny moves with the mouse movement.
+const SENSITIVITY: f32 = 0.25;
+
+/// Define a game state for our example.
+#[derive(Default)]
+struct GameState {
+    /// Horizontal position of the bunny.
+    x: f32,
+    /// Vertical position of the bunny.
+    y: f32,
+    /// Previous horizontal position of the bunny, for smooth rendering.
+    previous_x: f32,
+    /// Previous vertical position of the bunny, for smooth rendering.
+    previous_y: f32,
+    /// Whether the cursor is grabbed.
+    grabbed: bool,
+}
+
+impl Game for GameState {
+    /// Move the bunny with the mouse when the cursor is grabbed.
+    fn update(&mut self, ctx: Context) {
+        // Set the previous position before updating the current one
+        self.previous_x = self.x;
+        self.previous_y = self.y;
+
+        // Grab the cursor when clicking, hiding the cursor sprite
+        if !self.grabbed && ctx.mouse_pressed(MouseButton::Left) {
+            // Raw mouse motion works with both a locked and a confined cursor
+            self.grabbed = ctx.set_cursor_grab(CursorGrabMode::Locked).is_some();
+            if self.grabbed {
+                ctx.remove_cursor_sprite();
+                ctx.set_cursor_visible(false);
+            }
+        }
+
+        // Release the cursor again, showing the cursor sprite
+        if self.grabbed && ctx.key_pressed(KeyCode::Escape) {
+            self.grabbed = ctx.set_cursor_grab(CursorGrabMode::None).is_none();
+            ctx.set_cursor_sprite("cursor");
+        }
+
+        // Move the bunny with the raw mouse movement, which isn't stopped by the edges of the screen
+        if self.grabbed {
+            let (delta_x, delta_y) = ctx.mouse_motion();
+            self.x += delta_x * SENSITIVITY;
+            self.y += delta_y * SENSITIVITY;
+        }
+    }
+
+    /// Draw the bunny, the cursor sprite is drawn automatically.
+    fn render(&mut self, ctx: Context) {
+        ctx.sprite("bunnymark")
+            .translate((self.x, self.y))
+            .translate_previous((self.previous_x, self.previous_y))
+            .draw();
+
+        let text = if self.grabbed {
+            "Escape to release"
+        } else {
+            "Click to grab"
+        };
+        ctx.text("Beachball", text)
+            .use_ui_camera()
+            .translate((2.0, 2.0))
+            .draw();
+    }
+
+    /// Replace the OS cursor.
+    fn init(&mut self, ctx: Context) {
+        ctx.set_cursor_sprite("cursor");
+    }
+}
+
+/// Open an empty window.
+fn main() {
+    // Game configuration
+    let config = Config {
+        buffer_width: 240.0,
+        buffer_height: 192.0,
+        // Apply a minimum of 3 times scaling for the buffer
+        // Will result in a minimum, and on web exact, window size of 720x576
+        scaling: 3.0,
+        ..Default::default()
+    };
+
+    // Spawn the window and run the 'game'
+    GameState::default().run(chuot::load_assets!(), config);
+}
@@ -1,12 +1,16 @@
 //! Handle the state of connected gamepads.
 //!
 //! Show text for most button presses.
+//! Pressing the south button rumbles the gamepad when it supports force feedback.
 //! The state of the gamepads is checked in the `render` function, which is something you normally would not want to do, you would want to check it in the `update` function. But for this example that doesn't matter because the update delta time is low enough that it lasts multiple render functions.
 
-use chuot::{Config, Context, Game, GamepadAxis, GamepadButton};
+use chuot::{Config, Context, Game, GamepadAxis, GamepadButton, input::Stick};
 
-/// Define empty game state.
-struct GameState;
+/// Define game state.
+struct GameState {
+    /// Last connection or rumble message.
+    message: String,
+}
 
 impl Game for GameState {
     /// Show some text with gamepad buttons being pressed.
@@ -14,6 +18,12 @@ impl Game for GameState {
         // Position of the next line of text
         let mut line_y = 2.0;
 
+        // Show the last message at the bottom of the screen
+        ctx.text("Beachball", &self.message)
+            .use_ui_camera()
+            .translate((2.0, 178.0))
+            .draw();
+
         // Get all IDs of connected gamepads
         let connected_gamepad_ids = ctx.gamepad_ids();
 
@@ -26,8 +36,9 @@ impl Game for GameState {
 
         // Show the D-Pad button presses for each connected gamepad
         for (index, gamepad_id) in connected_gamepad_ids.iter().enumerate() {
-            // Draw the gamepad index
-            ctx.text("Beachball", &format!("Connected gamepad {}:", index + 1))
+            // Draw the gamepad index and name
+            let name = ctx.gamepad_name(*gamepad_id).unwrap_or_default();
+            ctx.text("Beachball", &format!("Gamepad {} '{name}':", index + 1))
                 .use_ui_camera()
                 .translate((2.0, line_y))
                 .draw();
@@ -110,17 +121,51 @@ impl Game for GameState {
                     }
                 }
             }
+
+            // Show the stick positions with the deadzones applied
+            for (stick, name) in [(Stick::Left, "Left Stick"), (Stick::Right, "Right Stick")] {
+                let (x, y) = ctx.gamepad_stick(*gamepad_id, stick);
+
+                // Only show when outside of the deadzone
+                if x != 0.0 || y != 0.0 {
+                    ctx.text("Beachball", &format!(" {name}: {x:.1}, {y:.1}"))
+                        .use_ui_camera()
+                        .translate((2.0, line_y))
+                        .draw();
+                    line_y += 12.0;
+                }
+            }
         }
     }
 
-    /// Do nothing during the update loop.
-    fn update(&mut self, _ctx: Context) {}
+    /// Track connections and rumble the gamepads.
+    fn update(&mut self, ctx: Context) {
+        // Show gamepads being connected and disconnected
+        for gamepad_id in ctx.gamepad_connected_ids() {
+            self.message = format!("Gamepad {gamepad_id} connected");
+        }
+        for gamepad_id in ctx.gamepad_disconnected_ids() {
+            self.message = format!("Gamepad {gamepad_id} disconnected");
+        }
+
+        // Rumble for half a second when the south button is pressed
+        for gamepad_id in ctx.gamepad_ids() {
+            if ctx.gamepad_button_pressed(gamepad_id, GamepadButton::South) == Some(true)
+                && !ctx.gamepad_rumble(gamepad_id, 0.5, 0.5)
+            {
+                self.message = format!("Gamepad {gamepad_id} can't rumble");
+            }
+        }
+    }
 }
 
 /// Run the game.
 fn main() {
     // Start the game with defaults for the window
-    GameState.run(
+    GameState {
+        message: String::new(),
+    }
+    .run(
         chuot::load_assets!(),
         Config::default()
             .with_buffer_size((240.0, 192.0))
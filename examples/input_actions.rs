@@ -0,0 +1,154 @@
+//! Show how to map keys, mouse buttons and gamepad inputs to named actions and axes.
+//!
+//! When developing on the desktop you can change the bindings live by editing the file, it will be hot-reloaded automatically.
+//!
+//! Press F1 to start and stop recording the input, and F2 to replay the recording.
+//!
+//! The `threeforms.png` sprite image for this example is:
+//! {{ img(src="/assets/threeforms.png" alt="Sprite") }}
+//! With the following `threeforms.ron` RON configuration file for positioning the center of the sprite:
+//! ```ron
+//! (pivot_x: Center, pivot_y: Center)
+//! ```
+//!
+//! The `bindings.ron` RON input bindings file for this example is:
+//! ```ron
+//! (
+//!     actions: {
+//!         "jump": ["Key(Space)", "Key(KeyW)", "GamepadButton(South)"],
+//!         "rebind": ["Key(KeyR)", "GamepadButton(North)"],
+//!     },
+//!     axes: {
+//!         "move_x": (
+//!             negative: ["Key(KeyA)", "Key(ArrowLeft)", "GamepadAxis(-LeftStickX)"],
+//!             positive: ["Key(KeyD)", "Key(ArrowRight)", "GamepadAxis(+LeftStickX)"],
+//!         ),
+//!     },
+//! )
+//! ```
+
+use chuot::{Config, Context, Game, KeyCode, input::InputRecording};
+
+/// How fast the sprite moves horizontally.
+const SPEED: f32 = 90.0;
+
+/// How fast the sprite moves up when jumping.
+const JUMP_VELOCITY: f32 = -150.0;
+
+/// How fast the sprite falls down.
+const GRAVITY: f32 = 400.0;
+
+/// Define a game state for our example.
+#[derive(Default)]
+struct GameState {
+    /// Horizontal position.
+    x: f32,
+    /// Height above the ground, negative is up.
+    y: f32,
+    /// Vertical velocity.
+    velocity_y: f32,
+    /// Whether we're waiting for a new binding for the jump action.
+    rebinding: bool,
+    /// Last recorded input.
+    recording: Option<InputRecording>,
+}
+
+impl Game for GameState {
+    /// Move the sprite with the actions.
+    fn update(&mut self, ctx: Context) {
+        // Start or stop recording the input, the replay also contains the key press stopping the recording so ignore it
+        if ctx.key_pressed(KeyCode::F1) && !ctx.is_replaying() {
+            if ctx.is_recording() {
+                self.recording = Some(ctx.stop_recording());
+            } else {
+                ctx.start_recording();
+            }
+        }
+
+        // Replay the recorded input, the replay must start from the same state for it to be the same
+        if ctx.key_pressed(KeyCode::F2) && !ctx.is_recording() {
+            if let Some(recording) = &self.recording {
+                self.x = 0.0;
+                self.y = 0.0;
+                self.velocity_y = 0.0;
+
+                ctx.replay(recording.clone());
+            }
+        }
+
+        // Wait for any input to bind it to the jump action
+        if self.rebinding {
+            if let Some(binding) = ctx.captured_input() {
+                ctx.unbind_action("jump");
+                ctx.bind_action("jump", binding);
+
+                self.rebinding = false;
+            }
+
+            return;
+        }
+
+        // Start capturing the next input
+        if ctx.action_pressed("rebind") {
+            ctx.capture_next_input();
+
+            self.rebinding = true;
+        }
+
+        // Move horizontally, with a gamepad the speed depends on how far the stick is moved
+        self.x += ctx.action_axis("move_x") * SPEED * ctx.delta_time();
+
+        // Jump when on the ground
+        if ctx.action_pressed("jump") && self.y >= 0.0 {
+            self.velocity_y = JUMP_VELOCITY;
+        }
+
+        // Fall back to the ground
+        self.velocity_y += GRAVITY * ctx.delta_time();
+        self.y = self.velocity_y.mul_add(ctx.delta_time(), self.y).min(0.0);
+    }
+
+    /// Render the game.
+    fn render(&mut self, ctx: Context) {
+        ctx.sprite("threeforms").translate((self.x, self.y)).draw();
+
+        // Show the bindings of the jump action
+        let text = if self.rebinding {
+            "Press any key or button..".to_owned()
+        } else if ctx.is_recording() {
+            "Recording..".to_owned()
+        } else if ctx.is_replaying() {
+            "Replaying..".to_owned()
+        } else {
+            let bindings = ctx.input_bindings();
+            let jump = bindings.actions.get("jump").into_iter().flatten();
+
+            format!(
+                "Jump: {}\nRebind: R\nRecord: F1\nReplay: F2",
+                jump.map(ToString::to_string).collect::<Vec<_>>().join(", ")
+            )
+        };
+        ctx.text("Beachball", &text).use_ui_camera().draw();
+    }
+
+    /// Load the bindings.
+    fn init(&mut self, ctx: Context) {
+        ctx.load_input_bindings("bindings");
+    }
+}
+
+/// Open an empty window.
+fn main() {
+    // Game configuration
+    let config = Config {
+        buffer_width: 240.0,
+        buffer_height: 192.0,
+        // Apply a minimum of 3 times scaling for the buffer
+        // Will result in a minimum, and on web exact, window size of 720x576
+        scaling: 3.0,
+        ..Default::default()
+    };
+
+    // Spawn the window and run the 'game'
+    GameState::default().run(chuot::load_assets!(), config);
+}
@@ -0,0 +1,98 @@
+//! Show how retro sound effects can be synthesized from RON files.
+//!
+//! Press the left mouse button for a laser, the right mouse button for a pickup and space for an explosion.
+//! Press F for a footstep, picked from a sound group with a random pitch and volume.
+//!
+//! The `laser.ron` sound effect file for this example is:
+//! ```ron
+//! (
+//!     waveform: Sawtooth,
+//!     sustain: 0.05,
+//!     punch: 0.3,
+//!     decay: 0.15,
+//!     frequency: 1200.0,
+//!     min_frequency: 100.0,
+//!     slide: -12.0,
+//!     volume: 0.3,
+//! )
+//! ```
+//!
+//! The `pickup.ron` sound effect file for this example is:
+//! ```ron
+//! (
+//!     waveform: Square,
+//!     sustain: 0.06,
+//!     punch: 0.4,
+//!     decay: 0.2,
+//!     frequency: 880.0,
+//!     arpeggio_multiplier: 1.5,
+//!     arpeggio_time: 0.06,
+//!     duty: 0.25,
+//!     volume: 0.3,
+//! )
+//! ```
+//!
+//! The `explosion.ron` sound effect file for this example is:
+//! ```ron
+//! (
+//!     waveform: Noise,
+//!     sustain: 0.1,
+//!     punch: 0.6,
+//!     decay: 0.5,
+//!     frequency: 400.0,
+//!     slide: -2.0,
+//!     vibrato_depth: 0.2,
+//!     vibrato_speed: 12.0,
+//! )
+//! ```
+//!
+//! The `footsteps.ron` sound group file for this example, with `footstep1.ron`, `footstep2.ron` and `footstep3.ron` sound effect files next to it, is:
+//! ```ron
+//! (clips: ["footstep1", "footstep2", "footstep3"])
+//! ```
+
+use chuot::{Config, Context, Game, KeyCode, MouseButton};
+
+/// Define a game state for our example.
+struct GameState;
+
+impl Game for GameState {
+    /// Handle input events to play the sound effects.
+    fn update(&mut self, ctx: Context) {
+        // Synthesize the sounds the first time they are played
+        if ctx.mouse_pressed(MouseButton::Left) {
+            // Vary the pitch slightly so repeated shots don't sound identical
+            ctx.audio("laser").with_pitch(0.9..=1.1).play();
+        }
+
+        if ctx.mouse_pressed(MouseButton::Right) {
+            ctx.audio("pickup").play();
+        }
+
+        if ctx.key_pressed(KeyCode::Space) {
+            ctx.audio("explosion").play();
+        }
+
+        if ctx.key_pressed(KeyCode::KeyF) {
+            // Pick a random clip from the group
+            ctx.audio("footsteps")
+                .with_pitch(0.8..=1.2)
+                .with_random_volume(0.7..=1.0)
+                .play();
+        }
+    }
+
+    /// Don't render anything.
+    fn render(&mut self, _ctx: Context) {}
+}
+
+/// Run the game.
+fn main() {
+    // Start the game with defaults for the window
+    GameState.run(
+        chuot::load_assets!(),
+        Config::default()
+            .with_buffer_size((720.0, 576.0))
+            .with_scaling(1.0),
+    );
+}
@@ -0,0 +1,196 @@
+//! Show how to render the same world with multiple cameras for local co-op games.
+//!
+//! Each camera has its own viewport on the buffer, the render function is called for every viewport.
+//! Press space to switch between splitting the screen horizontally and vertically.
+//!
+//! The `threeforms.png` sprite image for this example is:
+//! {{ img(src="/assets/threeforms.png" alt="Sprite") }}
+//! With the following `threeforms.ron` RON configuration file for positioning the center of the sprite:
+//! ```ron
+//! (pivot_x: Center, pivot_y: Center)
+//! ```
+//!
+//! The `bunnymark.png` sprite image for this example is:
+//! {{ img(src="/assets/bunnymark.png" alt="Sprite") }}
+//! With the following `bunnymark.ron` RON configuration file for positioning the center of the sprite:
+//! ```ron
+//! (pivot_x: Center, pivot_y: Center)
+//! ```
+
+use chuot::{Config, Context, Game, KeyCode};
+
+/// How fast the "players" move.
+const PLAYER_SPEED: f32 = 90.0;
+
+/// A single "player" with its own camera.
+#[derive(Default)]
+struct Player {
+    /// Horizontal position, the camera will follow this.
+    x: f32,
+    /// Vertical position, the camera will follow this.
+    y: f32,
+    /// Previous horizontal position, for smooth rendering.
+    previous_x: f32,
+    /// Previous vertical position, for smooth rendering.
+    previous_y: f32,
+}
+
+impl Player {
+    /// Move the player with the keys.
+    fn update(&mut self, ctx: &Context, left: KeyCode, right: KeyCode, up: KeyCode, down: KeyCode) {
+        // Set the previous position before updating the current one
+        self.previous_x = self.x;
+        self.previous_y = self.y;
+
+        if ctx.key_held(left) {
+            self.x -= PLAYER_SPEED * ctx.delta_time();
+        }
+        if ctx.key_held(right) {
+            self.x += PLAYER_SPEED * ctx.delta_time();
+        }
+        if ctx.key_held(up) {
+            self.y -= PLAYER_SPEED * ctx.delta_time();
+        }
+        if ctx.key_held(down) {
+            self.y += PLAYER_SPEED * ctx.delta_time();
+        }
+    }
+
+    /// Draw the player.
+    fn render(&self, ctx: &Context) {
+        ctx.sprite("threeforms")
+            .translate_x(self.x)
+            .translate_y(self.y)
+            .translate_previous_x(self.previous_x)
+            .translate_previous_y(self.previous_y)
+            .draw();
+    }
+}
+
+/// Define a game state for our example.
+#[derive(Default)]
+struct GameState {
+    /// First player on the left side of the screen.
+    left: Player,
+    /// Second player on the right side of the screen.
+    right: Player,
+    /// Whether the screen is split in a top and a bottom half instead of a left and a right half.
+    top_bottom: bool,
+}
+
+impl GameState {
+    /// Set the viewports of both cameras for the current split.
+    fn set_viewports(&self, ctx: &Context) {
+        if self.top_bottom {
+            // Split the screen horizontally in two halves
+            ctx.camera("left").set_viewport((0.0, 0.0), (360.0, 144.0));
+            ctx.camera("right")
+                .set_viewport((0.0, 144.0), (360.0, 144.0));
+        } else {
+            // Split the screen vertically in two halves
+            ctx.camera("left").set_viewport((0.0, 0.0), (180.0, 288.0));
+            ctx.camera("right")
+                .set_viewport((180.0, 0.0), (180.0, 288.0));
+        }
+    }
+}
+
+impl Game for GameState {
+    /// Move the "players" around with keyboard input.
+    fn update(&mut self, ctx: Context) {
+        // Switch between the splits
+        if ctx.key_pressed(KeyCode::Space) {
+            self.top_bottom = !self.top_bottom;
+            self.set_viewports(&ctx);
+        }
+
+        // Move the first player with 'wasd' and the second player with the arrow keys
+        self.left.update(
+            &ctx,
+            KeyCode::KeyA,
+            KeyCode::KeyD,
+            KeyCode::KeyW,
+            KeyCode::KeyS,
+        );
+        self.right.update(
+            &ctx,
+            KeyCode::ArrowLeft,
+            KeyCode::ArrowRight,
+            KeyCode::ArrowUp,
+            KeyCode::ArrowDown,
+        );
+
+        // Follow each player with its own camera
+        ctx.camera("left").follow((self.left.x, self.left.y));
+        ctx.camera("right").follow((self.right.x, self.right.y));
+    }
+
+    /// Render the game.
+    fn render(&mut self, ctx: Context) {
+        // Draw the world once for every camera
+        ctx.render_viewports(|camera| {
+            // Draw some bunnies to show the camera movement
+            ctx.sprite("bunnymark").draw_multiple_translated(
+                (-10..10).flat_map(|y| (-10..10).map(move |x| (x as f32 * 50.0, y as f32 * 50.0))),
+            );
+
+            // Draw both players in every viewport
+            self.left.render(&ctx);
+            self.right.render(&ctx);
+
+            // The UI camera is clipped to the viewport but not moved, so position it manually
+            let (x, y) = match (camera, self.top_bottom) {
+                ("left", _) => (0.0, 0.0),
+                (_, true) => (0.0, 144.0),
+                (_, false) => (180.0, 0.0),
+            };
+            ctx.text("Beachball", camera).translate((x, y)).draw();
+        });
+
+        // Draw a divider between the viewports, outside of the viewports it's not clipped
+        if self.top_bottom {
+            ctx.text("Beachball", &"-".repeat(45))
+                .translate_y(140.0)
+                .draw();
+        } else {
+            ctx.text(
+                "Beachball",
+                "|\n|\n|\n|\n|\n|\n|\n|\n|\n|\n|\n|\n|\n|\n|\n|\n|\n|",
+            )
+            .translate_x(176.0)
+            .draw();
+        }
+    }
+
+    /// Setup the cameras.
+    fn init(&mut self, ctx: Context) {
+        self.set_viewports(&ctx);
+    }
+}
+
+/// Open an empty window.
+fn main() {
+    // Game configuration
+    let config = Config {
+        buffer_width: 360.0,
+        buffer_height: 288.0,
+        // Apply a minimum of 2 times scaling for the buffer
+        // Will result in a minimum, and on web exact, window size of 720x576
+        scaling: 2.0,
+        ..Default::default()
+    };
+
+    // Spawn the window and run the 'game'
+    GameState {
+        left: Player {
+            x: -50.0,
+            ..Default::default()
+        },
+        right: Player {
+            x: 50.0,
+            ..Default::default()
+        },
+        top_bottom: false,
+    }
+    .run(chuot::load_assets!(), config);
+}
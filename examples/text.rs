@@ -12,7 +12,10 @@ use chuot::{Config, Context, Game};
 
 /// Define a game state for our example.
 #[derive(Default)]
-struct GameState;
+struct GameState {
+    /// Current rotation of the transformed text.
+    rotation: f32,
+}
 
 impl Game for GameState {
     /// Render the game.
@@ -33,10 +36,22 @@ impl Game for GameState {
             .glyph('@' as usize)
             // Draw the sprite on the screen, it is centered because by default it uses the main camera
             .draw();
+
+        // Text can be rotated and scaled as a whole around its pivot, just like sprites
+        ctx.text("Beachball", "Spin!")
+            // Place it in the bottom half of the screen
+            .translate_y(24.0)
+            // Rotate and scale around the center of the text
+            .pivot_center()
+            .rotate(self.rotation)
+            .scale((self.rotation.sin().mul_add(0.25, 1.0), 1.0))
+            .draw();
     }
 
-    /// Do nothing during the update loop.
-    fn update(&mut self, _ctx: Context) {}
+    /// Rotate the text.
+    fn update(&mut self, ctx: Context) {
+        self.rotation += ctx.delta_time();
+    }
 }
 
 /// Open an empty window.
@@ -52,5 +67,5 @@ fn main() {
     };
 
     // Spawn the window and run the 'game'
-    GameState.run(chuot::load_assets!(), config);
+    GameState::default().run(chuot::load_assets!(), config);
 }
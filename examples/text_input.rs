@@ -0,0 +1,96 @@
+//! Show how to type text using the keyboard layout, for example to enter a name for a high-score.
+//!
+//! The `Beachball.png` font image for this example is:
+//! {{ img(src="/assets/Beachball.png" alt="Font") }}
+
+use chuot::{Config, Context, Game, KeyCode};
+
+/// Maximum amount of characters in the name.
+const MAX_LENGTH: usize = 16;
+
+/// Define a game state for our example.
+#[derive(Default)]
+struct GameState {
+    /// Name being typed.
+    name: String,
+    /// Position of the text cursor in characters.
+    cursor: usize,
+}
+
+impl Game for GameState {
+    /// Handle the typed text.
+    fn update(&mut self, ctx: Context) {
+        // Insert the typed characters at the cursor
+        for character in ctx.text_input().chars() {
+            if self.name.chars().count() >= MAX_LENGTH {
+                break;
+            }
+
+            self.name.insert(self.byte_index(), character);
+            self.cursor += 1;
+        }
+
+        // Remove the character before the cursor, repeating when the key is held
+        if ctx.key_pressed_or_repeated(KeyCode::Backspace) && self.cursor > 0 {
+            self.cursor -= 1;
+            self.name.remove(self.byte_index());
+        }
+
+        // Move the cursor, repeating when the key is held
+        if ctx.key_pressed_or_repeated(KeyCode::ArrowLeft) {
+            self.cursor = self.cursor.saturating_sub(1);
+        }
+        if ctx.key_pressed_or_repeated(KeyCode::ArrowRight) {
+            self.cursor = (self.cursor + 1).min(self.name.chars().count());
+        }
+
+        // Clear the name when submitting it
+        if ctx.key_pressed(KeyCode::Enter) {
+            self.name.clear();
+            self.cursor = 0;
+        }
+    }
+
+    /// Render the typed text.
+    fn render(&mut self, ctx: Context) {
+        // Show the cursor as a character between the text
+        let mut text = self.name.clone();
+        text.insert(self.byte_index(), '|');
+
+        ctx.text("Beachball", &format!("Enter your name:\n{text}"))
+            .use_ui_camera()
+            .translate((2.0, 2.0))
+            .draw();
+    }
+
+    /// Allow typing with an input method editor.
+    fn init(&mut self, ctx: Context) {
+        ctx.set_ime_allowed(true);
+    }
+}
+
+impl GameState {
+    /// Convert the cursor position in characters to a position in bytes.
+    fn byte_index(&self) -> usize {
+        self.name
+            .char_indices()
+            .nth(self.cursor)
+            .map_or(self.name.len(), |(index, _)| index)
+    }
+}
+
+/// Open an empty window.
+fn main() {
+    // Game configuration
+    let config = Config {
+        buffer_width: 240.0,
+        buffer_height: 192.0,
+        // Apply a minimum of 3 times scaling for the buffer
+        // Will result in a minimum, and on web exact, window size of 720x576
+        scaling: 3.0,
+        ..Default::default()
+    };
+
+    // Spawn the window and run the 'game'
+    GameState::default().run(chuot::load_assets!(), config);
+}
@@ -0,0 +1,78 @@
+//! Show how to handle fingers touching the screen.
+//!
+//! Every finger draws a bunny, the first finger also acts as the left mouse button.
+//!
+//! The `bunnymark.png` sprite image for this example is:
+//! {{ img(src="/assets/bunnymark.png" alt="Sprite") }}
+//! With the following `bunnymark.ron` RON configuration file for positioning the center of the sprite:
+//! ```ron
+//! (pivot_x: Center, pivot_y: Center)
+//! ```
+
+use chuot::{Config, Context, Game, MouseButton};
+
+/// Define a game state for our example.
+#[derive(Default)]
+struct GameState {
+    /// How many fingers started touching the screen.
+    touches: u32,
+    /// How many times the emulated left mouse button is clicked.
+    clicks: u32,
+}
+
+impl Game for GameState {
+    /// Count the touches.
+    fn update(&mut self, ctx: Context) {
+        for touch_id in ctx.touch_ids() {
+            if ctx.touch_pressed(touch_id) {
+                self.touches += 1;
+            }
+        }
+
+        // Only the first finger is emulated as the mouse
+        if ctx.mouse_pressed(MouseButton::Left) {
+            self.clicks += 1;
+        }
+    }
+
+    /// Draw a bunny under every finger.
+    fn render(&mut self, ctx: Context) {
+        for touch_id in ctx.touch_ids() {
+            if let Some(position) = ctx.touch_position(touch_id) {
+                ctx.sprite("bunnymark")
+                    .use_ui_camera()
+                    .translate(position)
+                    .draw();
+            }
+        }
+
+        ctx.text(
+            "Beachball",
+            &format!("Touches: {}\nClicks: {}", self.touches, self.clicks),
+        )
+        .use_ui_camera()
+        .translate((2.0, 2.0))
+        .draw();
+    }
+
+    /// Emulate the mouse with the first finger.
+    fn init(&mut self, ctx: Context) {
+        ctx.set_touch_emulates_mouse(true);
+    }
+}
+
+/// Open an empty window.
+fn main() {
+    // Game configuration
+    let config = Config {
+        buffer_width: 240.0,
+        buffer_height: 192.0,
+        // Apply a minimum of 3 times scaling for the buffer
+        // Will result in a minimum, and on web exact, window size of 720x576
+        scaling: 3.0,
+        ..Default::default()
+    };
+
+    // Spawn the window and run the 'game'
+    GameState::default().run(chuot::load_assets!(), config);
+}
@@ -0,0 +1,90 @@
+//! Show how to draw immediate-mode user interface widgets.
+//!
+//! The widgets can also be navigated with the arrow keys or a gamepad, confirming with enter or the south button.
+//!
+//! The `button.png` sprite image for this example is stretched nine-slice style:
+//! {{ img(src="/assets/button.png" alt="Sprite") }}
+//! The `button_hover.png` and `button_pressed.png` sprite images are the same with different colors.
+
+use chuot::{Config, Context, Game, context::ui::UiStyle};
+
+/// Define a game state for our example.
+struct GameState {
+    /// How many times the button is clicked.
+    clicks: u32,
+    /// Value changed by the slider.
+    volume: f32,
+    /// Value toggled by the checkbox.
+    muted: bool,
+}
+
+impl Game for GameState {
+    /// Do nothing during the update loop.
+    fn update(&mut self, _ctx: Context) {}
+
+    /// Render the user interface.
+    fn render(&mut self, ctx: Context) {
+        // Move the widgets away from the border of the screen, leaving space for the focus indicator
+        ctx.ui().set_position((16.0, 8.0));
+
+        ctx.ui().label(&format!("Clicked {} times", self.clicks));
+
+        // Place the buttons next to each other
+        ctx.ui().horizontal(|ui| {
+            if ui.button("Click me") {
+                self.clicks += 1;
+            }
+
+            if ui.button("Reset") {
+                self.clicks = 0;
+            }
+        });
+
+        ctx.ui().slider(
+            &format!("Volume {:.0}%", self.volume * 100.0),
+            &mut self.volume,
+            0.0..=1.0,
+        );
+        ctx.ui().checkbox("Mute", &mut self.muted);
+
+        // Quit when the button is clicked or when escape or the east gamepad button is pressed
+        if ctx.ui().button("Quit") || ctx.ui().cancelled() {
+            ctx.exit();
+        }
+    }
+
+    /// Setup the style of the widgets.
+    fn init(&mut self, ctx: Context) {
+        ctx.ui().set_style(
+            UiStyle::new("Beachball")
+                .with_button_sprites("button", "button_hover", "button_pressed")
+                .with_checkbox_sprites("button", "button_pressed")
+                .with_slider_sprites("button_pressed", "button")
+                .with_padding(4.0),
+        );
+
+        // Focus the first button so the menu can be navigated without a mouse
+        ctx.ui().set_focus(Some(1));
+    }
+}
+
+/// Open an empty window.
+fn main() {
+    // Game configuration
+    let config = Config {
+        buffer_width: 240.0,
+        buffer_height: 192.0,
+        // Apply a minimum of 3 times scaling for the buffer
+        // Will result in a minimum, and on web exact, window size of 720x576
+        scaling: 3.0,
+        ..Default::default()
+    };
+
+    // Spawn the window and run the 'game'
+    GameState {
+        clicks: 0,
+        volume: 0.5,
+        muted: false,
+    }
+    .run(chuot::load_assets!(), config);
+}
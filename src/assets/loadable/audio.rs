@@ -2,21 +2,51 @@
 
 use kira::sound::static_sound::StaticSoundData;
 
-use super::Loadable;
+use super::{Loadable, sfx::SfxParams, sound_group::SoundGroup};
 use crate::{
-    assets::{Id, loader::ogg::OggLoader},
+    assets::{
+        Id,
+        loader::{Loader, flac::FlacLoader, ogg::OggLoader, wav::WavLoader},
+    },
     context::ContextInner,
 };
 
+/// Extensions of all supported audio files, in the order they are tried.
+pub(crate) const EXTENSIONS: [&str; 3] = [
+    <OggLoader as Loader<StaticSoundData>>::EXTENSION,
+    <WavLoader as Loader<StaticSoundData>>::EXTENSION,
+    <FlacLoader as Loader<StaticSoundData>>::EXTENSION,
+];
+
 /// Audio asset that can be loaded with metadata.
-pub(crate) struct Audio(pub(crate) StaticSoundData);
+pub(crate) enum Audio {
+    /// Decoded or synthesized sound.
+    ///
+    /// Boxed because it's a lot larger than the group.
+    Sound(Box<StaticSoundData>),
+    /// Group of sounds of which a random one is played.
+    Group(SoundGroup),
+}
 
 impl Loadable for Audio {
     fn load_if_exists(id: &Id, ctx: &mut ContextInner) -> Option<Self> {
-        let sound_data = ctx
-            .asset_source
-            .load_if_exists::<OggLoader, StaticSoundData>(id)?;
-
-        Some(Self(sound_data))
+        // Try every supported format
+        ctx.asset_source
+            .load_if_exists::<OggLoader, StaticSoundData>(id)
+            .or_else(|| {
+                ctx.asset_source
+                    .load_if_exists::<WavLoader, StaticSoundData>(id)
+            })
+            .or_else(|| {
+                ctx.asset_source
+                    .load_if_exists::<FlacLoader, StaticSoundData>(id)
+            })
+            .map(|sound_data| Self::Sound(Box::new(sound_data)))
+            // Pick from other sounds when there's no audio file
+            .or_else(|| SoundGroup::load_if_exists(id, ctx).map(Self::Group))
+            // Synthesize a sound effect when there's no audio file or group
+            .or_else(|| {
+                SfxParams::load_if_exists(id, ctx).map(|params| Self::Sound(Box::new(params.render())))
+            })
     }
 }
@@ -18,6 +18,81 @@ pub(crate) struct Font {
     pub(crate) metadata: FontMetadata,
 }
 
+impl Font {
+    /// Calculate the size of the text block when the text is laid out with this font.
+    ///
+    /// Returns `(width, height)` in pixels, where the width is that of the longest line.
+    pub(crate) fn text_size(&self, text: &str) -> (f32, f32) {
+        let FontMetadata {
+            glyph_width,
+            glyph_height,
+            ..
+        } = self.metadata;
+
+        let mut x = 0.0_f32;
+        let mut width = 0.0_f32;
+        let mut height = glyph_height;
+
+        for ch in text.chars() {
+            match ch {
+                '\n' => {
+                    x = 0.0;
+                    height += glyph_height;
+                }
+                '\t' => x += glyph_width * 4.0,
+                _ => x += glyph_width,
+            }
+
+            width = width.max(x);
+        }
+
+        (width, height)
+    }
+
+    /// Lay out the text, returning the position of each glyph relative to the top left of the text block.
+    ///
+    /// Characters that are not part of the font only move the cursor.
+    pub(crate) fn glyph_positions<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> impl Iterator<Item = (f32, f32, Sprite)> + 'a {
+        let FontMetadata {
+            glyph_width,
+            glyph_height,
+            first_char,
+            last_char,
+        } = self.metadata;
+
+        // Put the start position back 1 glyph since the first action is to move the cursor
+        let mut x = -glyph_width;
+        let mut y = 0.0;
+
+        text.chars().filter_map(move |ch| {
+            let char_index = ch as usize;
+
+            // Move the cursor
+            x += glyph_width;
+
+            // Don't draw characters that are not in the picture
+            if char_index < first_char || char_index > last_char {
+                if ch == '\n' {
+                    x = -glyph_width;
+                    y += glyph_height;
+                } else if ch == '\t' {
+                    x += glyph_width * 3.0;
+                }
+
+                return None;
+            }
+
+            // The sub rectangle offset of the character is based on the starting character and counted using the ASCII index
+            let char_offset = char_index - first_char;
+
+            Some((x, y, self.sprites[char_offset]))
+        })
+    }
+}
+
 impl Loadable for Font {
     fn load_if_exists(id: &Id, ctx: &mut ContextInner) -> Option<Self> {
         // Load the base sprite, but don't load the sprite metadata because we can only load one RON file
@@ -2,7 +2,9 @@
 
 pub(crate) mod audio;
 pub(crate) mod font;
+pub(crate) mod sfx;
 pub(crate) mod shader;
+pub(crate) mod sound_group;
 pub(crate) mod sprite;
 
 use downcast_rs::Downcast;
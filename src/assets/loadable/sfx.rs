@@ -0,0 +1,294 @@
+//! Procedurally generated retro sound effect asset.
+
+use std::f32::consts::TAU;
+
+use kira::{
+    Frame,
+    sound::static_sound::{StaticSoundData, StaticSoundSettings},
+};
+use nanoserde::DeRon;
+
+use super::Loadable;
+use crate::{
+    assets::{Id, loader::ron::RonLoader},
+    context::ContextInner,
+};
+
+/// Sample rate of the generated sound.
+const SAMPLE_RATE: u32 = 44_100;
+
+/// Amount of random samples generated for every period of the noise waveform.
+const NOISE_SAMPLES: usize = 32;
+
+/// Sound effect parameters to load from RON, synthesized like `sfxr`.
+///
+/// All fields are optional, the default is a short square wave beep.
+#[derive(Debug, Clone, Copy, PartialEq, DeRon)]
+pub struct SfxParams {
+    /// Shape of the wave.
+    #[nserde(default)]
+    pub(crate) waveform: Waveform,
+    /// Seconds it takes to fade in.
+    #[nserde(default = "0.0")]
+    pub(crate) attack: f32,
+    /// Seconds the sound stays at full volume after the attack.
+    #[nserde(default = "0.1")]
+    pub(crate) sustain: f32,
+    /// Extra volume at the start of the sustain in the range `0.0..=1.0`, fading out during the sustain.
+    #[nserde(default = "0.0")]
+    pub(crate) punch: f32,
+    /// Seconds it takes to fade out after the sustain.
+    #[nserde(default = "0.2")]
+    pub(crate) decay: f32,
+    /// Starting frequency in hertz.
+    #[nserde(default = "440.0")]
+    pub(crate) frequency: f32,
+    /// Frequency in hertz below which the sound stops, `0.0` never stops it.
+    #[nserde(default = "0.0")]
+    pub(crate) min_frequency: f32,
+    /// Octaves per second the frequency changes, negative slides down.
+    #[nserde(default = "0.0")]
+    pub(crate) slide: f32,
+    /// Octaves per second the slide itself changes every second.
+    #[nserde(default = "0.0")]
+    pub(crate) delta_slide: f32,
+    /// Fraction of the frequency the vibrato changes it.
+    #[nserde(default = "0.0")]
+    pub(crate) vibrato_depth: f32,
+    /// Vibrations per second.
+    #[nserde(default = "0.0")]
+    pub(crate) vibrato_speed: f32,
+    /// Frequency multiplication factor applied once by the arpeggio, for example `1.5` for a fifth up.
+    #[nserde(default = "1.0")]
+    pub(crate) arpeggio_multiplier: f32,
+    /// Seconds after which the arpeggio is applied, `0.0` disables it.
+    #[nserde(default = "0.0")]
+    pub(crate) arpeggio_time: f32,
+    /// Fraction of the period the square wave is high, `0.5` is a pure square wave.
+    #[nserde(default = "0.5")]
+    pub(crate) duty: f32,
+    /// Change of the duty every second.
+    #[nserde(default = "0.0")]
+    pub(crate) duty_sweep: f32,
+    /// Volume multiplication factor in the range `0.0..=1.0`.
+    #[nserde(default = "0.5")]
+    pub(crate) volume: f32,
+}
+
+impl SfxParams {
+    /// Synthesize the sound.
+    pub(crate) fn render(&self) -> StaticSoundData {
+        let dt = (SAMPLE_RATE as f32).recip();
+        let length = self.attack + self.sustain + self.decay;
+        let total_frames = (length * SAMPLE_RATE as f32).ceil() as usize;
+
+        // Use the same seed every time so reloading the sound doesn't change it
+        let mut rng = fastrand::Rng::with_seed(0);
+        let mut noise = [0.0; NOISE_SAMPLES];
+        noise.fill_with(|| rng.f32().mul_add(2.0, -1.0));
+
+        // State changing while the sound is playing
+        let mut phase = 0.0;
+        let mut frequency = self.frequency;
+        let mut slide = self.slide;
+        let mut duty = self.duty;
+        let mut arpeggio_applied = false;
+
+        let mut frames = Vec::with_capacity(total_frames);
+        for index in 0..total_frames {
+            let time = index as f32 * dt;
+
+            // Apply the arpeggio once
+            if !arpeggio_applied && self.arpeggio_time > 0.0 && time >= self.arpeggio_time {
+                frequency *= self.arpeggio_multiplier;
+                arpeggio_applied = true;
+            }
+
+            // Slide the frequency, stopping the sound when it's too low
+            slide += self.delta_slide * dt;
+            frequency *= (slide * dt).exp2();
+            if frequency < self.min_frequency {
+                break;
+            }
+
+            // Sweep the duty of the square wave
+            duty = self.duty_sweep.mul_add(dt, duty).clamp(0.0, 1.0);
+
+            // Advance the wave, with the vibrato changing the speed
+            let vibrato = (TAU * self.vibrato_speed * time)
+                .sin()
+                .mul_add(self.vibrato_depth, 1.0);
+            phase += frequency * vibrato * dt;
+            if phase >= 1.0 {
+                phase %= 1.0;
+
+                // Every period has different noise
+                noise.fill_with(|| rng.f32().mul_add(2.0, -1.0));
+            }
+
+            // Sample the wave
+            let sample = match self.waveform {
+                Waveform::Square => {
+                    if phase < duty {
+                        1.0
+                    } else {
+                        -1.0
+                    }
+                }
+                Waveform::Sawtooth => phase.mul_add(-2.0, 1.0),
+                Waveform::Sine => (TAU * phase).sin(),
+                Waveform::Triangle => (phase - 0.5).abs().mul_add(4.0, -1.0),
+                Waveform::Noise => noise[(phase * NOISE_SAMPLES as f32) as usize % NOISE_SAMPLES],
+            };
+
+            frames.push(Frame::from_mono(sample * self.envelope(time) * self.volume));
+        }
+
+        StaticSoundData {
+            sample_rate: SAMPLE_RATE,
+            frames: frames.into(),
+            settings: StaticSoundSettings::default(),
+            slice: None,
+        }
+    }
+
+    /// Volume of the attack, sustain and decay at a moment in the sound.
+    fn envelope(&self, time: f32) -> f32 {
+        if time < self.attack {
+            // Fade in, never reached without an attack so it can't divide by zero
+            time / self.attack
+        } else if time < self.attack + self.sustain {
+            // Fade out the punch
+            let progress = (time - self.attack) / self.sustain;
+
+            self.punch.mul_add(1.0 - progress, 1.0)
+        } else if self.decay > 0.0 {
+            // Fade out
+            let progress = (time - self.attack - self.sustain) / self.decay;
+
+            (1.0 - progress).max(0.0)
+        } else {
+            // Silent after the sustain without a decay
+            0.0
+        }
+    }
+}
+
+impl Default for SfxParams {
+    /// Same as the defaults of the RON fields.
+    fn default() -> Self {
+        Self {
+            waveform: Waveform::default(),
+            attack: 0.0,
+            sustain: 0.1,
+            punch: 0.0,
+            decay: 0.2,
+            frequency: 440.0,
+            min_frequency: 0.0,
+            slide: 0.0,
+            delta_slide: 0.0,
+            vibrato_depth: 0.0,
+            vibrato_speed: 0.0,
+            arpeggio_multiplier: 1.0,
+            arpeggio_time: 0.0,
+            duty: 0.5,
+            duty_sweep: 0.0,
+            volume: 0.5,
+        }
+    }
+}
+
+impl Loadable for SfxParams {
+    #[inline]
+    fn load_if_exists(id: &Id, ctx: &mut ContextInner) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        ctx.asset_source.load_if_exists::<RonLoader, _>(id)
+    }
+}
+
+/// Shape of the wave of a synthesized sound effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, DeRon)]
+pub enum Waveform {
+    /// Hollow retro sound, changed by the duty.
+    #[default]
+    Square,
+    /// Harsh buzzing sound.
+    Sawtooth,
+    /// Pure soft tone.
+    Sine,
+    /// Soft tone with a bit more bite than a sine.
+    Triangle,
+    /// Random noise, for explosions and hits.
+    Noise,
+}
+
+#[cfg(test)]
+mod tests {
+    use nanoserde::DeRon as _;
+
+    use super::{SAMPLE_RATE, SfxParams};
+
+    /// Amount of frames a sound lasts without stopping early.
+    fn expected_frames(params: &SfxParams) -> usize {
+        ((params.attack + params.sustain + params.decay) * SAMPLE_RATE as f32).ceil() as usize
+    }
+
+    #[test]
+    fn default_matches_ron() {
+        assert_eq!(
+            SfxParams::deserialize_ron("()").ok(),
+            Some(SfxParams::default())
+        );
+    }
+
+    #[test]
+    fn frame_count() {
+        let params = SfxParams {
+            attack: 0.05,
+            sustain: 0.1,
+            decay: 0.25,
+            ..SfxParams::default()
+        };
+
+        assert_eq!(params.render().frames.len(), expected_frames(&params));
+    }
+
+    #[test]
+    fn stop_at_min_frequency() {
+        // Sliding two octaves down takes a fifth of a second
+        let params = SfxParams {
+            sustain: 1.0,
+            slide: -10.0,
+            min_frequency: 110.0,
+            ..SfxParams::default()
+        };
+
+        let frames = params.render().frames.len();
+        assert!(frames < expected_frames(&params));
+        assert!(frames.abs_diff(SAMPLE_RATE as usize / 5) <= 2);
+    }
+
+    #[test]
+    fn without_attack_and_decay() {
+        let params = SfxParams {
+            attack: 0.0,
+            punch: 0.5,
+            decay: 0.0,
+            ..SfxParams::default()
+        };
+
+        let sound = params.render();
+        assert_eq!(sound.frames.len(), expected_frames(&params));
+        assert!(
+            sound
+                .frames
+                .iter()
+                .all(|frame| frame.left.is_finite() && frame.left.abs() <= 1.0)
+        );
+
+        // Starts at full volume with the punch
+        assert!((sound.frames[0].left.abs() - 0.75).abs() < f32::EPSILON);
+    }
+}
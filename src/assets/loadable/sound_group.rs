@@ -0,0 +1,113 @@
+//! Group of audio clips of which a random one is played.
+
+use std::cell::Cell;
+
+use nanoserde::DeRon;
+
+use super::Loadable;
+use crate::{
+    assets::{
+        Id,
+        loader::{Loader, ron::RonLoader},
+    },
+    context::ContextInner,
+};
+
+/// Sound group playing a random clip every time.
+pub(crate) struct SoundGroup {
+    /// Asset paths of the audio clips to pick from.
+    clips: Vec<String>,
+    /// Index of the clip that's played last, so it's not repeated.
+    previous: Cell<Option<usize>>,
+}
+
+impl SoundGroup {
+    /// Pick a random clip that's different from the previous one.
+    pub(crate) fn pick(&self) -> &str {
+        let count = self.clips.len();
+
+        let index = match self.previous.get() {
+            // Skip over the previous clip
+            Some(previous) if count > 1 => {
+                let index = fastrand::usize(..count - 1);
+
+                if index >= previous { index + 1 } else { index }
+            }
+            _ => fastrand::usize(..count),
+        };
+        self.previous.set(Some(index));
+
+        &self.clips[index]
+    }
+}
+
+impl Loadable for SoundGroup {
+    fn load_if_exists(id: &Id, ctx: &mut ContextInner) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        // Don't use the RON loader, other audio assets are also defined in RON files
+        let bytes = ctx
+            .asset_source
+            .bytes_if_exists(id, <RonLoader as Loader<SoundGroupMetadata>>::EXTENSION)?;
+        let SoundGroupMetadata { clips } =
+            SoundGroupMetadata::deserialize_ron(&String::from_utf8_lossy(&bytes)).ok()?;
+
+        assert!(
+            !clips.is_empty(),
+            "Error loading sound group with ID '{id}': no clips"
+        );
+
+        Some(Self {
+            clips,
+            previous: Cell::new(None),
+        })
+    }
+}
+
+/// Sound group metadata to load from RON.
+#[derive(Debug, Clone, DeRon)]
+struct SoundGroupMetadata {
+    /// Asset paths of the audio clips to pick from.
+    clips: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::SoundGroup;
+
+    /// Pick many times and assert that the same clip is never picked twice in a row.
+    fn assert_no_repeats(count: usize) {
+        let group = SoundGroup {
+            clips: (0..count).map(|index| index.to_string()).collect(),
+            previous: Cell::new(None),
+        };
+
+        let mut previous = group.pick().to_owned();
+        for _ in 0..1000 {
+            let clip = group.pick();
+            assert_ne!(clip, previous);
+
+            clip.clone_into(&mut previous);
+        }
+    }
+
+    #[test]
+    fn pick_without_repeats() {
+        assert_no_repeats(2);
+        assert_no_repeats(3);
+    }
+
+    #[test]
+    fn pick_single_clip() {
+        let group = SoundGroup {
+            clips: vec!["clip".to_owned()],
+            previous: Cell::new(None),
+        };
+
+        assert_eq!(group.pick(), "clip");
+        assert_eq!(group.pick(), "clip");
+    }
+}
@@ -1,8 +1,10 @@
 //! Asset loader helper.
 
+pub mod flac;
 pub mod ogg;
 pub mod png;
 pub mod ron;
+pub mod wav;
 
 use super::Id;
 
@@ -47,7 +47,7 @@ impl Loader<(u32, u32, Vec<RGBA8>)> for PngLoader {
         );
 
         // Read the PNG
-        let mut pixels = vec![RGBA8::default(); reader.output_buffer_size()];
+        let mut pixels = vec![RGBA8::default(); reader.output_buffer_size().unwrap()];
         let info = reader
             .next_frame(bytemuck::cast_slice_mut(&mut pixels))
             .expect("Error reading image");
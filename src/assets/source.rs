@@ -1,6 +1,7 @@
 //! How and where assets are loaded.
 
 use std::{
+    borrow::Cow,
     path::{MAIN_SEPARATOR, PathBuf},
     str::FromStr as _,
 };
@@ -106,32 +107,49 @@ impl AssetSource {
     where
         L: Loader<T>,
     {
+        let bytes = self.bytes_if_exists(id, L::EXTENSION)?;
+
+        // Create object
+        Some(L::load(&bytes, id))
+    }
+
+    /// Get the raw bytes of an asset from memory or from disk if it exists.
+    #[must_use]
+    #[inline]
+    pub(crate) fn bytes_if_exists(&self, id: &Id, extension: &str) -> Option<Cow<'static, [u8]>> {
         // First try to read from memory
-        // TODO: use a map for this
-        if let Some(bytes) = self.embedded_assets.iter().find_map(|raw_asset| {
-            (raw_asset.id == id && raw_asset.extension == L::EXTENSION).then_some(raw_asset.bytes)
-        }) {
-            // Create object
-            return Some(L::load(bytes, id));
+        if let Some(bytes) = self.embedded_bytes(id, extension) {
+            return Some(Cow::Borrowed(bytes));
         }
 
         // If not found load from disk if dir set
-        if let Some(runtime_asset_dir) = &self.runtime_asset_dir {
-            // Convert ID back to file
-            let file_path = runtime_asset_dir.join(format!(
-                "{}.{}",
-                id.replace('.', std::str::from_utf8(&[MAIN_SEPARATOR as u8]).unwrap()),
-                L::EXTENSION
-            ));
+        let file_path = self.runtime_path(id, extension)?;
 
-            // Read the file, return None if it failed for whatever reason
-            let bytes = std::fs::read(file_path).ok()?;
+        // Read the file, return None if it failed for whatever reason
+        std::fs::read(file_path).ok().map(Cow::Owned)
+    }
 
-            // Create object
-            Some(L::load(&bytes, id))
-        } else {
-            None
-        }
+    /// Get the raw bytes of an embedded asset if it exists.
+    #[must_use]
+    #[inline]
+    pub(crate) fn embedded_bytes(&self, id: &Id, extension: &str) -> Option<&'static [u8]> {
+        // TODO: use a map for this
+        self.embedded_assets.iter().find_map(|raw_asset| {
+            (raw_asset.id == id && raw_asset.extension == extension).then_some(raw_asset.bytes)
+        })
+    }
+
+    /// Get the path of an asset on disk in the runtime asset directory, if the directory is set.
+    #[must_use]
+    #[inline]
+    pub(crate) fn runtime_path(&self, id: &Id, extension: &str) -> Option<PathBuf> {
+        // Convert ID back to file
+        self.runtime_asset_dir.as_ref().map(|runtime_asset_dir| {
+            runtime_asset_dir.join(format!(
+                "{}.{extension}",
+                id.replace('.', std::str::from_utf8(&[MAIN_SEPARATOR as u8]).unwrap()),
+            ))
+        })
     }
 
     /// Get the texture for an embedded texture if it exists.
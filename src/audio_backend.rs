@@ -0,0 +1,175 @@
+//! Audio output that falls back to silently mixing when there's no audio device.
+
+use kira::{
+    AudioManager, AudioManagerSettings, DefaultBackend, PlaySoundError, ResourceLimitReached,
+    backend::mock::{MockBackend, MockBackendSettings},
+    clock::{ClockHandle, ClockSpeed},
+    sound::SoundData,
+    track::{MainTrackHandle, TrackBuilder, TrackHandle},
+};
+
+/// Sample rate of the silent mixer, low because nothing is heard.
+const SILENT_SAMPLE_RATE: u32 = 8000;
+
+/// Amount of frames mixed at once, must match the internal buffer size of the audio manager.
+const SILENT_BUFFER_SIZE: usize = 128;
+
+/// Audio manager playing on the audio device, or mixing silently when there's none.
+///
+/// The silent mixer is advanced every update tick, so sounds still finish and clocks still tick.
+pub(crate) enum AudioBackend {
+    /// Audio is played on the default audio device.
+    ///
+    /// Both audio managers are boxed because they differ a lot in size.
+    Device(Box<AudioManager<DefaultBackend>>),
+    /// Audio is mixed without any output.
+    Silent {
+        /// Audio manager without an audio device.
+        audio_manager: Box<AudioManager<MockBackend>>,
+        /// Seconds that still need to be mixed.
+        accumulator: f32,
+    },
+}
+
+impl AudioBackend {
+    /// Connect to the default audio device, or mix silently when that fails.
+    pub(crate) fn new() -> Self {
+        // Try to play on the audio device
+        match AudioManager::<DefaultBackend>::new(AudioManagerSettings::default()) {
+            Ok(audio_manager) => Self::Device(Box::new(audio_manager)),
+            Err(err) => {
+                eprintln!("Error opening audio device, audio is disabled: {err}");
+
+                Self::silent()
+            }
+        }
+    }
+
+    /// Mix silently without connecting to an audio device.
+    pub(crate) fn silent() -> Self {
+        let mut audio_manager = AudioManager::<MockBackend>::new(AudioManagerSettings {
+            internal_buffer_size: SILENT_BUFFER_SIZE,
+            backend_settings: MockBackendSettings {
+                sample_rate: SILENT_SAMPLE_RATE,
+            },
+            ..Default::default()
+        })
+        .expect("Mock audio backend can't fail");
+
+        // Apply the initial state
+        audio_manager.backend_mut().on_start_processing();
+
+        Self::Silent {
+            audio_manager: Box::new(audio_manager),
+            accumulator: 0.0,
+        }
+    }
+
+    /// Whether audio is played on an audio device.
+    pub(crate) const fn is_available(&self) -> bool {
+        matches!(self, Self::Device(_))
+    }
+
+    /// Play a sound on the main track.
+    pub(crate) fn play<D>(&mut self, sound_data: D) -> Result<D::Handle, PlaySoundError<D::Error>>
+    where
+        D: SoundData,
+    {
+        match self {
+            Self::Device(audio_manager) => audio_manager.play(sound_data),
+            Self::Silent { audio_manager, .. } => audio_manager.play(sound_data),
+        }
+    }
+
+    /// Add a mixer sub-track.
+    pub(crate) fn add_sub_track(
+        &mut self,
+        builder: TrackBuilder,
+    ) -> Result<TrackHandle, ResourceLimitReached> {
+        match self {
+            Self::Device(audio_manager) => audio_manager.add_sub_track(builder),
+            Self::Silent { audio_manager, .. } => audio_manager.add_sub_track(builder),
+        }
+    }
+
+    /// Add a clock.
+    pub(crate) fn add_clock(
+        &mut self,
+        speed: ClockSpeed,
+    ) -> Result<ClockHandle, ResourceLimitReached> {
+        match self {
+            Self::Device(audio_manager) => audio_manager.add_clock(speed),
+            Self::Silent { audio_manager, .. } => audio_manager.add_clock(speed),
+        }
+    }
+
+    /// Main mixer track all sounds and sub-tracks end up in.
+    pub(crate) fn main_track(&mut self) -> &mut MainTrackHandle {
+        match self {
+            Self::Device(audio_manager) => audio_manager.main_track(),
+            Self::Silent { audio_manager, .. } => audio_manager.main_track(),
+        }
+    }
+
+    /// Mix the time that passed when there's no audio device, the audio device mixes by itself.
+    ///
+    /// Must be done in the update tick.
+    pub(crate) fn update(&mut self, delta_time: f32) {
+        let Self::Silent {
+            audio_manager,
+            accumulator,
+        } = self
+        else {
+            return;
+        };
+
+        // Apply the changes since the previous update tick
+        let backend = audio_manager.backend_mut();
+        backend.on_start_processing();
+
+        // Mix whole buffers until caught up
+        let buffer_duration = SILENT_BUFFER_SIZE as f32 / SILENT_SAMPLE_RATE as f32;
+        *accumulator += delta_time;
+        while *accumulator >= buffer_duration {
+            backend.process();
+
+            *accumulator -= buffer_duration;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kira::{clock::ClockSpeed, sound::PlaybackState};
+
+    use super::AudioBackend;
+    use crate::assets::loadable::sfx::SfxParams;
+
+    #[test]
+    fn silent_mixing_advances() {
+        let mut audio = AudioBackend::silent();
+        assert!(!audio.is_available());
+
+        let mut clock = audio
+            .add_clock(ClockSpeed::TicksPerSecond(10.0))
+            .expect("Error adding clock");
+        clock.start();
+
+        let sound = audio
+            .play(SfxParams::default().render())
+            .expect("Error playing sound");
+
+        // The default sound lasts 0.3 seconds
+        let mut updates = 0;
+        while sound.state() != PlaybackState::Stopped {
+            assert!(updates < 60, "Sound didn't stop playing");
+
+            audio.update(1.0 / 60.0);
+            updates += 1;
+        }
+        assert!(updates > 10);
+
+        // The clock ticks with the mixed time
+        assert!(clock.time().ticks >= 2);
+    }
+}
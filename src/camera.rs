@@ -1,5 +1,18 @@
 //! The camera system for both UI and the game camera.
 
+use glam::{Affine2, Vec2};
+
+/// Reference to one of the cameras.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum CameraId<'name> {
+    /// Main game camera.
+    Main,
+    /// Camera for drawing user interfaces.
+    Ui,
+    /// User created camera.
+    Named(&'name str),
+}
+
 /// Camera for offsetting sprites.
 pub(crate) struct Camera {
     /// Current horizontal position.
@@ -14,6 +27,26 @@ pub(crate) struct Camera {
     offset_x: f32,
     /// Screen vertical offset.
     offset_y: f32,
+    /// Horizontal position of the item the camera is following.
+    follow_x: f32,
+    /// Vertical position of the item the camera is following.
+    follow_y: f32,
+    /// Whether a position to follow has been set.
+    following: bool,
+    /// Position of the item the camera is following in the previous update tick, `None` when there's no previous position to calculate the velocity from.
+    prev_follow: Option<Vec2>,
+    /// Horizontal center of the deadzone the followed item can move in without moving the camera.
+    focus_x: f32,
+    /// Vertical center of the deadzone the followed item can move in without moving the camera.
+    focus_y: f32,
+    /// Size of the deadzone box around the focus point, zero disables the deadzone.
+    deadzone: Vec2,
+    /// Seconds of velocity of the followed item the camera looks ahead.
+    look_ahead: f32,
+    /// Maximum distance in pixels the camera looks ahead.
+    look_ahead_max_distance: f32,
+    /// Minimum and maximum world positions of the visible area, if the camera is clamped.
+    bounds: Option<(Vec2, Vec2)>,
     /// Target horizontal position.
     target_x: f32,
     /// Target vertical position.
@@ -38,6 +71,30 @@ pub(crate) struct Camera {
     shake_amplitude: f32,
     /// Reciprocal of shake frequency in Hertz.
     shake_frequency_recip: f32,
+    /// Current zoom factor.
+    zoom: f32,
+    /// Zoom factor calculated for rendering.
+    render_zoom: f32,
+    /// Target zoom factor.
+    target_zoom: f32,
+    /// Previous target zoom factor.
+    prev_target_zoom: f32,
+    /// How fast to interpolate between the zoom factors.
+    lerp_zoom: f32,
+    /// Current rotation in radians.
+    rotation: f32,
+    /// Rotation calculated for rendering.
+    render_rotation: f32,
+    /// Target rotation in radians.
+    target_rotation: f32,
+    /// Previous target rotation in radians.
+    prev_target_rotation: f32,
+    /// How fast to interpolate between the rotations.
+    lerp_rotation: f32,
+    /// Rectangle `(x, y, width, height)` on the buffer the camera renders to, `None` is the whole buffer.
+    viewport: Option<(f32, f32, f32, f32)>,
+    /// Transformation from world space to buffer space calculated for rendering.
+    render_matrix: Affine2,
 }
 
 impl Camera {
@@ -45,13 +102,51 @@ impl Camera {
     ///
     /// Must be done in the update tick.
     #[inline]
-    pub(crate) fn update_target(&mut self) {
+    pub(crate) fn update_target(&mut self, dt: f32, buffer_width: f32, buffer_height: f32) {
+        // The visible area is the size of the viewport when set
+        let (width, height) = self.viewport_size(buffer_width, buffer_height);
+
+        // Move the focus point when the followed item leaves the deadzone
+        let half_deadzone = self.deadzone / 2.0;
+        self.focus_x = self.focus_x.clamp(
+            self.follow_x - half_deadzone.x,
+            self.follow_x + half_deadzone.x,
+        );
+        self.focus_y = self.focus_y.clamp(
+            self.follow_y - half_deadzone.y,
+            self.follow_y + half_deadzone.y,
+        );
+
+        // Look ahead in the direction the followed item is moving, the first followed position has no velocity
+        let follow = Vec2::new(self.follow_x, self.follow_y);
+        let velocity = self
+            .prev_follow
+            .map_or(Vec2::ZERO, |prev_follow| (follow - prev_follow) / dt);
+        let look_ahead =
+            (velocity * self.look_ahead).clamp_length_max(self.look_ahead_max_distance);
+        self.prev_follow = self.following.then_some(follow);
+
+        // Keep the target inside the bounds
+        (self.target_x, self.target_y) = self.clamp_to_bounds(
+            self.focus_x + look_ahead.x,
+            self.focus_y + look_ahead.y,
+            width,
+            height,
+        );
+
         self.prev_target_x = self.x;
         self.prev_target_y = self.y;
+        self.prev_target_zoom = self.zoom;
+        self.prev_target_rotation = self.rotation;
 
         // // Interpolate with the lerp factor
         self.x = crate::math::lerp(self.x, self.target_x, self.lerp_x);
         self.y = crate::math::lerp(self.y, self.target_y, self.lerp_y);
+        self.zoom = crate::math::lerp(self.zoom, self.target_zoom, self.lerp_zoom);
+        self.rotation = crate::math::lerp(self.rotation, self.target_rotation, self.lerp_rotation);
+
+        // Changing the zoom can move the visible area outside of the bounds
+        (self.x, self.y) = self.clamp_to_bounds(self.x, self.y, width, height);
     }
 
     /// Update the camera.
@@ -61,6 +156,9 @@ impl Camera {
         // Interpolate the targets with the blending factor, to reduce jitters
         self.render_x = crate::math::lerp(self.prev_target_x, self.x, blending_factor);
         self.render_y = crate::math::lerp(self.prev_target_y, self.y, blending_factor);
+        self.render_zoom = crate::math::lerp(self.prev_target_zoom, self.zoom, blending_factor);
+        self.render_rotation =
+            crate::math::lerp(self.prev_target_rotation, self.rotation, blending_factor);
 
         // Apply camera shake
         if self.shake_current_duration > 0.0 {
@@ -90,6 +188,8 @@ impl Camera {
                 self.shake_y = Shake::default();
             }
         }
+
+        self.update_render_matrix();
     }
 
     /// Set the horizontal lerp.
@@ -104,28 +204,162 @@ impl Camera {
         self.lerp_y = lerp_y;
     }
 
-    /// Set the target horizontal position.
+    /// Set the horizontal position of the item to follow.
     #[inline]
-    pub(crate) fn set_target_x(&mut self, x: f32) {
-        self.target_x = x;
+    pub(crate) const fn set_follow_x(&mut self, x: f32) {
+        self.follow_x = x;
+        self.following = true;
     }
 
-    /// Set the target vertical position.
+    /// Set the vertical position of the item to follow.
     #[inline]
-    pub(crate) fn set_target_y(&mut self, y: f32) {
-        self.target_y = y;
+    pub(crate) const fn set_follow_y(&mut self, y: f32) {
+        self.follow_y = y;
+        self.following = true;
     }
 
-    /// How much to offset the horizontal position of the item to draw.
+    /// Forget the previous position of the item to follow, so the next position doesn't result in a velocity.
     #[inline]
-    pub(crate) fn offset_x(&self) -> f32 {
-        -self.render_x + self.offset_x + self.shake_x.value()
+    pub(crate) const fn reset_follow(&mut self) {
+        self.prev_follow = None;
     }
 
-    /// How much to offset the vertical position of the item to draw.
+    /// Set the size of the deadzone box.
     #[inline]
-    pub(crate) fn offset_y(&self) -> f32 {
-        -self.render_y + self.offset_y + self.shake_y.value()
+    pub(crate) const fn set_deadzone(&mut self, width: f32, height: f32) {
+        self.deadzone = Vec2::new(width, height);
+    }
+
+    /// Set how far the camera looks ahead in the direction of movement.
+    #[inline]
+    pub(crate) const fn set_look_ahead(&mut self, look_ahead: f32, max_distance: f32) {
+        self.look_ahead = look_ahead;
+        self.look_ahead_max_distance = max_distance;
+    }
+
+    /// Set the area the camera can see, or `None` to be unbounded.
+    #[inline]
+    pub(crate) const fn set_bounds(&mut self, bounds: Option<(Vec2, Vec2)>) {
+        self.bounds = bounds;
+    }
+
+    /// Set the zoom lerp.
+    #[inline]
+    pub(crate) const fn set_lerp_zoom(&mut self, lerp_zoom: f32) {
+        self.lerp_zoom = lerp_zoom;
+    }
+
+    /// Set the rotation lerp.
+    #[inline]
+    pub(crate) const fn set_lerp_rotation(&mut self, lerp_rotation: f32) {
+        self.lerp_rotation = lerp_rotation;
+    }
+
+    /// Set the target zoom factor.
+    #[inline]
+    pub(crate) const fn set_target_zoom(&mut self, zoom: f32) {
+        self.target_zoom = zoom;
+    }
+
+    /// Set the target rotation.
+    #[inline]
+    pub(crate) const fn set_target_rotation(&mut self, rotation: f32) {
+        self.target_rotation = rotation;
+    }
+
+    /// Set the zoom factor immediately without interpolating.
+    #[inline]
+    pub(crate) fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom;
+        self.render_zoom = zoom;
+        self.target_zoom = zoom;
+        self.prev_target_zoom = zoom;
+
+        self.update_render_matrix();
+    }
+
+    /// Set the rotation immediately without interpolating.
+    #[inline]
+    pub(crate) fn set_rotation(&mut self, rotation: f32) {
+        self.rotation = rotation;
+        self.render_rotation = rotation;
+        self.target_rotation = rotation;
+        self.prev_target_rotation = rotation;
+
+        self.update_render_matrix();
+    }
+
+    /// Current zoom factor.
+    #[inline]
+    pub(crate) const fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Current rotation in radians.
+    #[inline]
+    pub(crate) const fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    /// Transformation from world space to buffer space, applied to each item to draw.
+    #[inline]
+    pub(crate) const fn affine_matrix(&self) -> Affine2 {
+        self.render_matrix
+    }
+
+    /// Map a coordinate on the buffer back to world space.
+    #[inline]
+    pub(crate) fn inverse_transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        self.render_matrix
+            .inverse()
+            .transform_point2(Vec2::new(x, y))
+            .into()
+    }
+
+    /// Set the rectangle on the buffer the camera renders to and center the camera in it.
+    #[inline]
+    pub(crate) fn set_viewport(
+        &mut self,
+        viewport: Option<(f32, f32, f32, f32)>,
+        buffer_width: f32,
+        buffer_height: f32,
+    ) {
+        self.viewport = viewport;
+
+        let (width, height) = self.viewport_size(buffer_width, buffer_height);
+        self.center(width, height);
+    }
+
+    /// Rectangle on the buffer the camera renders to, `None` is the whole buffer.
+    #[inline]
+    pub(crate) const fn viewport(&self) -> Option<(f32, f32, f32, f32)> {
+        self.viewport
+    }
+
+    /// Size of the area the camera renders to.
+    #[inline]
+    pub(crate) fn viewport_size(&self, buffer_width: f32, buffer_height: f32) -> (f32, f32) {
+        self.viewport
+            .map_or((buffer_width, buffer_height), |(_, _, width, height)| {
+                (width, height)
+            })
+    }
+
+    /// Offset in buffer pixels of a world position from the center of the area the camera renders to.
+    #[inline]
+    pub(crate) fn offset_from_center(
+        &self,
+        x: f32,
+        y: f32,
+        buffer_width: f32,
+        buffer_height: f32,
+    ) -> (f32, f32) {
+        // Center of the viewport on the buffer
+        let (viewport_x, viewport_y, _, _) = self.viewport.unwrap_or_default();
+        let (width, height) = self.viewport_size(buffer_width, buffer_height);
+        let center = Vec2::new(viewport_x + width / 2.0, viewport_y + height / 2.0);
+
+        (self.render_matrix.transform_point2(Vec2::new(x, y)) - center).into()
     }
 
     /// Center the camera at the middle of the screen.
@@ -133,6 +367,8 @@ impl Camera {
     pub(crate) fn center(&mut self, buffer_width: f32, buffer_height: f32) {
         self.offset_x = buffer_width / 2.0;
         self.offset_y = buffer_height / 2.0;
+
+        self.update_render_matrix();
     }
 
     /// Center the camera at the top left corner of the screen.
@@ -140,6 +376,8 @@ impl Camera {
     pub(crate) fn top_left(&mut self) {
         self.offset_x = 0.0;
         self.offset_y = 0.0;
+
+        self.update_render_matrix();
     }
 
     /// Shake the camera.
@@ -149,6 +387,51 @@ impl Camera {
         self.shake_amplitude = amplitude;
         self.shake_frequency_recip = frequency.recip();
     }
+
+    /// Clamp the position so the visible area stays inside the bounds.
+    ///
+    /// When the bounds are smaller than the visible area the position is centered in the bounds.
+    fn clamp_to_bounds(&self, x: f32, y: f32, buffer_width: f32, buffer_height: f32) -> (f32, f32) {
+        let Some((min, max)) = self.bounds else {
+            return (x, y);
+        };
+
+        // Calculate the visible area in world space around the camera position, ignoring rotation
+        let before = Vec2::new(self.offset_x, self.offset_y) / self.zoom;
+        let after =
+            Vec2::new(buffer_width - self.offset_x, buffer_height - self.offset_y) / self.zoom;
+
+        // Limit both axes, centering when the area doesn't fit
+        let clamp_axis = |value: f32, min: f32, max: f32, before: f32, after: f32| {
+            if max - min < before + after {
+                (min + max) / 2.0 + (before - after) / 2.0
+            } else {
+                value.clamp(min + before, max - after)
+            }
+        };
+
+        (
+            clamp_axis(x, min.x, max.x, before.x, after.x),
+            clamp_axis(y, min.y, max.y, before.y, after.y),
+        )
+    }
+
+    /// Recalculate the render transformation from the render state.
+    fn update_render_matrix(&mut self) {
+        // Position of the viewport on the buffer
+        let (viewport_x, viewport_y, _, _) = self.viewport.unwrap_or_default();
+
+        // Move the screen offset and shake last so it always stays aligned with the buffer
+        self.render_matrix = Affine2::from_translation(Vec2::new(
+            viewport_x + self.offset_x + self.shake_x.value(),
+            viewport_y + self.offset_y + self.shake_y.value(),
+        ))
+            // Rotate and zoom around the position of the camera
+            * Affine2::from_angle(self.render_rotation)
+            * Affine2::from_scale(Vec2::splat(self.render_zoom))
+            // Move the world so the camera position is at the origin
+            * Affine2::from_translation(Vec2::new(-self.render_x, -self.render_y));
+    }
 }
 
 impl Default for Camera {
@@ -158,6 +441,16 @@ impl Default for Camera {
             y: 0.0,
             render_x: 0.0,
             render_y: 0.0,
+            follow_x: 0.0,
+            follow_y: 0.0,
+            following: false,
+            prev_follow: None,
+            focus_x: 0.0,
+            focus_y: 0.0,
+            deadzone: Vec2::ZERO,
+            look_ahead: 0.0,
+            look_ahead_max_distance: 0.0,
+            bounds: None,
             target_x: 0.0,
             target_y: 0.0,
             prev_target_x: 0.0,
@@ -172,6 +465,18 @@ impl Default for Camera {
             shake_current_duration: 0.0,
             shake_amplitude: 0.0,
             shake_frequency_recip: 0.0,
+            zoom: 1.0,
+            render_zoom: 1.0,
+            target_zoom: 1.0,
+            prev_target_zoom: 1.0,
+            lerp_zoom: 0.3,
+            rotation: 0.0,
+            render_rotation: 0.0,
+            target_rotation: 0.0,
+            prev_target_rotation: 0.0,
+            lerp_rotation: 0.3,
+            viewport: None,
+            render_matrix: Affine2::IDENTITY,
         }
     }
 }
@@ -0,0 +1,133 @@
+//! Named musical clocks sounds can be synchronized to.
+
+use kira::{
+    Tween,
+    clock::{ClockHandle, ClockSpeed, ClockTime},
+};
+
+use crate::audio_backend::AudioBackend;
+
+/// Moment on a clock a sound can start at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Quantize {
+    /// Start of the next beat.
+    Beat,
+    /// Start of the next bar.
+    Bar,
+}
+
+/// Clock ticking every beat on the audio thread, so it doesn't drift from the music.
+pub(crate) struct BeatClock {
+    /// Clock on the audio manager.
+    handle: ClockHandle,
+    /// Amount of beats in a single bar.
+    beats_per_bar: u32,
+    /// Beat in the previous update tick, `None` when the clock didn't tick yet.
+    previous_beat: Option<u64>,
+    /// Whether the beat changed since the previous update tick.
+    new_beat: bool,
+}
+
+impl BeatClock {
+    /// Create a new clock on the audio manager, it doesn't tick until it's started.
+    ///
+    /// # Panics
+    ///
+    /// - When the maximum amount of clocks is reached.
+    pub(crate) fn new(audio_manager: &mut AudioBackend, bpm: f32, beats_per_bar: u32) -> Self {
+        let handle = audio_manager
+            .add_clock(ClockSpeed::TicksPerMinute(f64::from(bpm)))
+            .expect("Error creating clock");
+
+        Self {
+            handle,
+            beats_per_bar: beats_per_bar.max(1),
+            previous_beat: None,
+            new_beat: false,
+        }
+    }
+
+    /// Change the tempo.
+    pub(crate) fn set_bpm(&mut self, bpm: f32, tween: Tween) {
+        self.handle
+            .set_speed(ClockSpeed::TicksPerMinute(f64::from(bpm)), tween);
+    }
+
+    /// Change the amount of beats in a single bar.
+    pub(crate) fn set_beats_per_bar(&mut self, beats_per_bar: u32) {
+        self.beats_per_bar = beats_per_bar.max(1);
+    }
+
+    /// Start or resume ticking.
+    pub(crate) fn start(&mut self) {
+        self.handle.start();
+    }
+
+    /// Pause ticking.
+    pub(crate) fn pause(&mut self) {
+        self.handle.pause();
+    }
+
+    /// Pause ticking and reset to the first beat.
+    pub(crate) fn stop(&mut self) {
+        self.handle.stop();
+        self.previous_beat = None;
+    }
+
+    /// Whether the clock is ticking.
+    pub(crate) fn is_ticking(&self) -> bool {
+        self.handle.ticking()
+    }
+
+    /// Amount of beats since the clock started.
+    pub(crate) fn beat(&self) -> u64 {
+        self.handle.time().ticks
+    }
+
+    /// Progress towards the next beat in the range `0.0..1.0`.
+    pub(crate) fn beat_fraction(&self) -> f32 {
+        self.handle.time().fraction as f32
+    }
+
+    /// Amount of beats in a single bar.
+    pub(crate) const fn beats_per_bar(&self) -> u32 {
+        self.beats_per_bar
+    }
+
+    /// Whether the beat changed since the previous update tick.
+    pub(crate) const fn is_new_beat(&self) -> bool {
+        self.new_beat
+    }
+
+    /// Time on the clock at the start of the next beat or bar.
+    pub(crate) fn next(&self, quantize: Quantize) -> ClockTime {
+        let beat = self.beat();
+        let ticks = match quantize {
+            Quantize::Beat => beat + 1,
+            Quantize::Bar => {
+                let beats_per_bar = u64::from(self.beats_per_bar);
+
+                (beat / beats_per_bar + 1) * beats_per_bar
+            }
+        };
+
+        ClockTime::from_ticks_u64(self.handle.id(), ticks)
+    }
+
+    /// Detect beat changes.
+    ///
+    /// Must be done in the update tick.
+    pub(crate) fn update(&mut self) {
+        // Nothing changes while paused
+        if !self.is_ticking() {
+            self.new_beat = false;
+
+            return;
+        }
+
+        // The first beat is also new
+        let beat = self.beat();
+        self.new_beat = self.previous_beat != Some(beat);
+        self.previous_beat = Some(beat);
+    }
+}
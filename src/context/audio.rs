@@ -1,8 +1,27 @@
 //! Zero-cost abstraction types for building more complicated audio playbacks.
 
-use kira::{Decibels, Panning, sound::Region};
+use std::{ops::RangeInclusive, time::Duration};
 
-use crate::Context;
+use kira::{
+    Decibels, Mix, Panning, PlaybackRate, StartTime, Tween,
+    sound::{PlaybackState, Region, static_sound::StaticSoundHandle},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use kira::sound::{FromFileError, streaming::StreamingSoundHandle};
+
+/// Re-exported [`kira`](https://docs.rs/kira) type.
+pub use kira::effect::filter::FilterMode;
+
+pub use crate::spatial::{Falloff, SpatialSettings};
+use crate::{Context, clock::Quantize, mixer::MixerTrack, random, spatial::Emitter};
+
+/// Reference to a sound that's playing, returned by [`AudioContext::play`].
+///
+/// Can be freely copied and stored, use [`Context::sound`] to control the sound.
+/// Once the sound is finished the handle is invalid and all operations on it are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SoundHandle(pub(crate) u32);
 
 /// Specify how an audio clip should be played.
 ///
@@ -22,9 +41,21 @@ pub struct AudioContext<'path, 'ctx> {
     pub(crate) loop_region: Option<Region>,
     /// Which part of the song to play.
     pub(crate) playback_region: Option<Region>,
+    /// Whether to stream the song from the asset instead of decoding it fully.
+    pub(crate) streaming: bool,
+    /// Name of the mixer track to play on, `None` plays on the main track.
+    pub(crate) track: Option<&'path str>,
+    /// Position in the world the sound is played at.
+    pub(crate) position: Option<(f32, f32)>,
+    /// Range of the random playback rate.
+    pub(crate) pitch: Option<(f32, f32)>,
+    /// Range of the random volume multiplication factor.
+    pub(crate) random_volume: Option<(f32, f32)>,
+    /// Name of the clock and the moment on it to start playing.
+    pub(crate) start_on: Option<(&'path str, Quantize)>,
 }
 
-impl AudioContext<'_, '_> {
+impl<'path> AudioContext<'path, '_> {
     /// Set the volume of the sound.
     ///
     /// # Arguments
@@ -51,6 +82,34 @@ impl AudioContext<'_, '_> {
         self
     }
 
+    /// Play the sound with a random pitch, so repeated sounds like footsteps don't sound identical.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Range of the playback rate multiplication factor, for example `0.9..=1.1`, `2.0` is twice as fast and an octave higher.
+    #[inline(always)]
+    #[must_use]
+    pub fn with_pitch(mut self, range: RangeInclusive<f32>) -> Self {
+        self.pitch = Some(range.into_inner());
+
+        self
+    }
+
+    /// Play the sound with a random volume, so repeated sounds like gunshots don't sound identical.
+    ///
+    /// The random factor is multiplied with the volume set by [`Self::with_volume`].
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Range of the volume multiplication factor, for example `0.8..=1.0`.
+    #[inline(always)]
+    #[must_use]
+    pub fn with_random_volume(mut self, range: RangeInclusive<f32>) -> Self {
+        self.random_volume = Some(range.into_inner());
+
+        self
+    }
+
     /// Loop the whole song.
     ///
     /// This is equivalent to [`Self::with_loop_region(..)`].
@@ -88,27 +147,188 @@ impl AudioContext<'_, '_> {
         self
     }
 
+    /// Play the sound on a named mixer track instead of the main track.
+    ///
+    /// The volume and effects of the track are applied to all sounds playing on it, see [`Context::track`].
+    ///
+    /// # Arguments
+    ///
+    /// * `track` - Name of the mixer track, created when it doesn't exist yet.
+    #[inline(always)]
+    #[must_use]
+    pub const fn on_track(mut self, track: &'path str) -> Self {
+        self.track = Some(track);
+
+        self
+    }
+
+    /// Play the sound at a position in the world.
+    ///
+    /// The panning and volume are calculated from the distance to the center of the main camera, this overwrites [`Self::pan`].
+    /// The volume set with [`Self::with_volume`] is multiplied by the falloff, see [`Context::set_spatial_audio_settings`].
+    ///
+    /// While playing the panning and volume follow the camera, move the sound with [`SoundContext::set_position`].
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Horizontal position in the world.
+    /// * `y` - Vertical position in the world.
+    #[inline(always)]
+    #[must_use]
+    pub const fn at(mut self, x: f32, y: f32) -> Self {
+        self.position = Some((x, y));
+
+        self
+    }
+
+    /// Start playing exactly at the start of the next beat of a clock, instead of immediately.
+    ///
+    /// The sound waits while the clock is paused, see [`Context::clock`].
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - Name of the clock.
+    #[inline(always)]
+    #[must_use]
+    pub const fn on_next_beat(mut self, clock: &'path str) -> Self {
+        self.start_on = Some((clock, Quantize::Beat));
+
+        self
+    }
+
+    /// Start playing exactly at the start of the next bar of a clock, instead of immediately.
+    ///
+    /// The sound waits while the clock is paused, see [`Context::clock`].
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - Name of the clock.
+    #[inline(always)]
+    #[must_use]
+    pub const fn on_next_bar(mut self, clock: &'path str) -> Self {
+        self.start_on = Some((clock, Quantize::Bar));
+
+        self
+    }
+
     /// Play the audio from start to end.
     ///
+    /// # Returns
+    ///
+    /// - Handle to control the sound while it's playing with [`Context::sound`], can be ignored.
+    ///
     /// # Panics
     ///
     /// - When asset failed loading.
+    /// - When a sound group contains another sound group.
     /// - When the sound could not be played on the manager.
+    /// - When the clock set with [`Self::on_next_beat`] or [`Self::on_next_bar`] doesn't exist.
     #[inline(always)]
-    pub fn play(self) {
+    #[allow(clippy::must_use_candidate)]
+    pub fn play(mut self) -> SoundHandle {
         self.ctx.write(|ctx| {
-            // Get the sound data and its settings
-            let sound_data = &ctx.audio(self.path).0;
+            // Randomize the volume and the pitch
+            if let Some((min, max)) = self.random_volume {
+                self.volume = Some(self.volume.unwrap_or(1.0) * random(min, max));
+            }
+            let playback_rate = self
+                .pitch
+                .map(|(min, max)| PlaybackRate(f64::from(random(min, max))));
+
+            // Calculate the moment on the clock to start
+            let start_time = self.start_on.map(|(clock, quantize)| {
+                let Some(clock) = ctx.clocks.get(clock) else {
+                    panic!("Error playing audio: clock '{clock}' doesn't exist");
+                };
+
+                StartTime::ClockTime(clock.next(quantize))
+            });
+
+            // Calculate the volume and panning from the position in the world
+            let emitter = self.position.map(|(x, y)| {
+                let volume = self.volume.unwrap_or(1.0);
+                let (gain, panning) = ctx.spatialize(x, y);
+                self.volume = Some(volume * gain);
+                self.panning = Some(panning);
+
+                Emitter {
+                    x,
+                    y,
+                    volume,
+                    gain,
+                    panning,
+                }
+            });
+
+            // Stream the sound, not supported on the web
+            #[cfg(not(target_arch = "wasm32"))]
+            if self.streaming {
+                let mut sound_data = ctx.music(self.path);
+
+                // Set the volume
+                if let Some(volume) = self.volume {
+                    sound_data = sound_data.volume(volume_to_decibels(volume));
+                }
+
+                // Set the panning
+                if let Some(panning) = self.panning {
+                    sound_data = sound_data.panning(panning_from_balance(panning));
+                }
+
+                // Set the pitch
+                if let Some(playback_rate) = playback_rate {
+                    sound_data = sound_data.playback_rate(playback_rate);
+                }
+
+                // Wait for the clock
+                if let Some(start_time) = start_time {
+                    sound_data = sound_data.start_time(start_time);
+                }
+
+                // Set the loop region
+                if let Some(loop_region) = self.loop_region {
+                    sound_data = sound_data.loop_region(loop_region);
+                }
+
+                // Set the playback region slice
+                if let Some(playback_region) = self.playback_region {
+                    sound_data = sound_data.slice(playback_region);
+                }
+
+                let handle = ctx
+                    .play_sound(self.track, sound_data)
+                    .expect("Error playing music");
+
+                let handle = ctx.insert_sound(PlayingSound::Streaming(handle));
+                if let Some(emitter) = emitter {
+                    ctx.emitters.insert(handle, emitter);
+                }
+
+                return handle;
+            }
+
+            // Get the sound data and its settings, picking a clip when it's a sound group
+            let sound_data = ctx.sound_data(self.path);
             let mut settings = sound_data.settings;
 
             // Set the volume
             if let Some(volume) = self.volume {
-                settings = settings.volume(Decibels::from(volume));
+                settings = settings.volume(volume_to_decibels(volume));
             }
 
             // Set the panning
             if let Some(panning) = self.panning {
-                settings = settings.panning(Panning::from(panning));
+                settings = settings.panning(panning_from_balance(panning));
+            }
+
+            // Set the pitch
+            if let Some(playback_rate) = playback_rate {
+                settings = settings.playback_rate(playback_rate);
+            }
+
+            // Wait for the clock
+            if let Some(start_time) = start_time {
+                settings = settings.start_time(start_time);
             }
 
             // Set the loop region
@@ -124,11 +344,306 @@ impl AudioContext<'_, '_> {
                 sound_data = sound_data.slice(playback_region);
             }
 
-            ctx.audio_manager
-                .play(sound_data)
+            let handle = ctx
+                .play_sound(self.track, sound_data)
                 .expect("Error playing audio");
+
+            let handle = ctx.insert_sound(PlayingSound::Static(handle));
+            if let Some(emitter) = emitter {
+                ctx.emitters.insert(handle, emitter);
+            }
+
+            handle
+        })
+    }
+}
+
+/// Handle of a sound playing on the audio manager.
+pub(crate) enum PlayingSound {
+    /// Sound decoded fully into memory.
+    Static(StaticSoundHandle),
+    /// Sound decoded while it's playing.
+    #[cfg(not(target_arch = "wasm32"))]
+    Streaming(StreamingSoundHandle<FromFileError>),
+}
+
+/// Call the same method on every kind of sound handle.
+macro_rules! with_sound {
+    ($playing_sound:expr, $sound:ident => $call:expr) => {
+        match $playing_sound {
+            PlayingSound::Static($sound) => $call,
+            #[cfg(not(target_arch = "wasm32"))]
+            PlayingSound::Streaming($sound) => $call,
+        }
+    };
+}
+
+impl PlayingSound {
+    /// Current playback state.
+    pub(crate) fn state(&self) -> PlaybackState {
+        with_sound!(self, sound => sound.state())
+    }
+
+    /// Current position in seconds.
+    pub(crate) fn position(&self) -> f64 {
+        with_sound!(self, sound => sound.position())
+    }
+
+    /// Stop the sound.
+    pub(crate) fn stop(&mut self, tween: Tween) {
+        with_sound!(self, sound => sound.stop(tween));
+    }
+
+    /// Pause the sound.
+    pub(crate) fn pause(&mut self, tween: Tween) {
+        with_sound!(self, sound => sound.pause(tween));
+    }
+
+    /// Resume the paused sound.
+    pub(crate) fn resume(&mut self, tween: Tween) {
+        with_sound!(self, sound => sound.resume(tween));
+    }
+
+    /// Jump to a position in seconds.
+    pub(crate) fn seek_to(&mut self, position: f64) {
+        with_sound!(self, sound => sound.seek_to(position));
+    }
+
+    /// Jump forwards or backwards in seconds.
+    pub(crate) fn seek_by(&mut self, amount: f64) {
+        with_sound!(self, sound => sound.seek_by(amount));
+    }
+
+    /// Tween the volume.
+    pub(crate) fn set_volume(&mut self, volume: Decibels, tween: Tween) {
+        with_sound!(self, sound => sound.set_volume(volume, tween));
+    }
+
+    /// Tween the panning.
+    pub(crate) fn set_panning(&mut self, panning: Panning, tween: Tween) {
+        with_sound!(self, sound => sound.set_panning(panning, tween));
+    }
+
+    /// Tween the playback rate.
+    pub(crate) fn set_playback_rate(&mut self, playback_rate: PlaybackRate, tween: Tween) {
+        with_sound!(self, sound => sound.set_playback_rate(playback_rate, tween));
+    }
+}
+
+/// Control a sound that's playing.
+///
+/// Used by [`Context::sound`].
+pub struct SoundContext<'ctx> {
+    /// Reference to the context the sound is playing in.
+    pub(crate) ctx: &'ctx Context,
+    /// Which sound to control.
+    pub(crate) handle: SoundHandle,
+}
+
+impl SoundContext<'_> {
+    /// Stop the sound, it can't be resumed after.
+    ///
+    /// # Arguments
+    ///
+    /// * `fade_out` - Seconds it takes for the volume to fade to silence, `0.0` stops immediately.
+    #[inline]
+    pub fn stop(&self, fade_out: f32) {
+        self.ctx.write(|ctx| {
+            if let Some(sound) = ctx.sounds.get_mut(&self.handle) {
+                sound.stop(tween(fade_out));
+            }
+        });
+    }
+
+    /// Pause the sound, it can be resumed with [`Self::resume`].
+    ///
+    /// # Arguments
+    ///
+    /// * `fade_out` - Seconds it takes for the volume to fade to silence, `0.0` pauses immediately.
+    #[inline]
+    pub fn pause(&self, fade_out: f32) {
+        self.ctx.write(|ctx| {
+            if let Some(sound) = ctx.sounds.get_mut(&self.handle) {
+                sound.pause(tween(fade_out));
+            }
+        });
+    }
+
+    /// Resume the sound after it's paused with [`Self::pause`].
+    ///
+    /// # Arguments
+    ///
+    /// * `fade_in` - Seconds it takes for the volume to fade back in, `0.0` resumes immediately.
+    #[inline]
+    pub fn resume(&self, fade_in: f32) {
+        self.ctx.write(|ctx| {
+            if let Some(sound) = ctx.sounds.get_mut(&self.handle) {
+                sound.resume(tween(fade_in));
+            }
         });
     }
+
+    /// Jump to a position in the sound.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Seconds from the start of the sound.
+    #[inline]
+    pub fn seek_to(&self, position: f32) {
+        self.ctx.write(|ctx| {
+            if let Some(sound) = ctx.sounds.get_mut(&self.handle) {
+                sound.seek_to(f64::from(position));
+            }
+        });
+    }
+
+    /// Jump forwards or backwards in the sound.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Seconds to jump, negative to jump backwards.
+    #[inline]
+    pub fn seek_by(&self, amount: f32) {
+        self.ctx.write(|ctx| {
+            if let Some(sound) = ctx.sounds.get_mut(&self.handle) {
+                sound.seek_by(f64::from(amount));
+            }
+        });
+    }
+
+    /// Change the volume of the sound while it's playing.
+    ///
+    /// For sounds played with [`AudioContext::at`] the volume is multiplied by the falloff.
+    ///
+    /// # Arguments
+    ///
+    /// * `volume` - Volume multiplication factor in the range `0.0..=1.0`.
+    /// * `duration` - Seconds it takes to smoothly reach the new volume, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_volume(&self, volume: f32, duration: f32) {
+        self.ctx.write(|ctx| {
+            // Apply the falloff of positioned sounds
+            let gain = ctx.emitters.get_mut(&self.handle).map_or(1.0, |emitter| {
+                emitter.volume = volume;
+
+                emitter.gain
+            });
+
+            if let Some(sound) = ctx.sounds.get_mut(&self.handle) {
+                sound.set_volume(volume_to_decibels(volume * gain), tween(duration));
+            }
+        });
+    }
+
+    /// Move a sound played with [`AudioContext::at`] to follow a moving object.
+    ///
+    /// The panning and volume are updated in the next update tick.
+    /// Does nothing for sounds that aren't played at a position.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Horizontal position in the world.
+    /// * `y` - Vertical position in the world.
+    #[inline]
+    pub fn set_position(&self, x: f32, y: f32) {
+        self.ctx.write(|ctx| {
+            if let Some(emitter) = ctx.emitters.get_mut(&self.handle) {
+                emitter.x = x;
+                emitter.y = y;
+            }
+        });
+    }
+
+    /// Change the panning of the sound while it's playing.
+    ///
+    /// For sounds played with [`AudioContext::at`] the panning is overwritten when the sound or the camera moves.
+    ///
+    /// # Arguments
+    ///
+    /// * `panning` - Which of the stereo speakers to use, `0.0` is hard left, `1.0` is hard right and `0.5` is both equally.
+    /// * `duration` - Seconds it takes to smoothly reach the new panning, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_panning(&self, panning: f32, duration: f32) {
+        self.ctx.write(|ctx| {
+            if let Some(sound) = ctx.sounds.get_mut(&self.handle) {
+                sound.set_panning(panning_from_balance(panning), tween(duration));
+            }
+        });
+    }
+
+    /// Change the playback rate of the sound while it's playing, which also changes the pitch.
+    ///
+    /// # Arguments
+    ///
+    /// * `playback_rate` - Speed multiplication factor, `1.0` is the original speed and `2.0` is twice as fast and an octave higher.
+    /// * `duration` - Seconds it takes to smoothly reach the new playback rate, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_playback_rate(&self, playback_rate: f32, duration: f32) {
+        self.ctx.write(|ctx| {
+            if let Some(sound) = ctx.sounds.get_mut(&self.handle) {
+                sound.set_playback_rate(PlaybackRate(f64::from(playback_rate)), tween(duration));
+            }
+        });
+    }
+
+    /// Whether the sound can still be heard.
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the sound is playing, including when it's fading in or out.
+    /// - `false` when the sound is paused, finished or stopped.
+    #[inline]
+    #[must_use]
+    pub fn is_playing(&self) -> bool {
+        self.state().is_some_and(|state| {
+            matches!(
+                state,
+                PlaybackState::Playing
+                    | PlaybackState::Pausing
+                    | PlaybackState::Resuming
+                    | PlaybackState::Stopping
+            )
+        })
+    }
+
+    /// Whether the sound is paused with [`Self::pause`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the sound is paused and can be resumed.
+    #[inline]
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.state().is_some_and(|state| {
+            matches!(
+                state,
+                PlaybackState::Paused | PlaybackState::WaitingToResume
+            )
+        })
+    }
+
+    /// Current position in the sound.
+    ///
+    /// # Returns
+    ///
+    /// - `None` when the sound is finished or stopped.
+    /// - `Some(..)` with the seconds from the start of the sound.
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> Option<f32> {
+        self.ctx.read(|ctx| {
+            ctx.sounds
+                .get(&self.handle)
+                .filter(|sound| sound.state() != PlaybackState::Stopped)
+                .map(|sound| sound.position() as f32)
+        })
+    }
+
+    /// Playback state of the sound, `None` when the handle is no longer valid.
+    fn state(&self) -> Option<PlaybackState> {
+        self.ctx
+            .read(|ctx| ctx.sounds.get(&self.handle).map(PlayingSound::state))
+    }
 }
 
 /// Audio methods.
@@ -138,9 +653,48 @@ impl Context {
     /// This will load the audio asset from disk.
     /// Check the [`AudioContext`] documentation for drawing options available.
     ///
+    /// When no audio file exists but a `.ron` file with the same path lists clips, it's a sound group.
+    /// Every time a sound group is played a random clip from it is played, never the same one twice in a row.
+    /// The clips can't be sound groups themselves:
+    ///
+    /// ```ron
+    /// (clips: ["footstep1", "footstep2", "footstep3"])
+    /// ```
+    ///
+    /// Otherwise the `.ron` file is synthesized into a retro sound effect, like `sfxr`.
+    /// Every field is optional and changing the file while hot-reloading changes the sound the next time it's played:
+    ///
+    /// ```ron
+    /// (
+    ///   // Square, Sawtooth, Sine, Triangle or Noise
+    ///   waveform: Square,
+    ///   // Envelope in seconds, punch is extra volume fading out during the sustain
+    ///   attack: 0.0,
+    ///   sustain: 0.1,
+    ///   punch: 0.0,
+    ///   decay: 0.2,
+    ///   // Frequency in hertz, the sound stops when it slides below the minimum
+    ///   frequency: 440.0,
+    ///   min_frequency: 0.0,
+    ///   // Octaves per second and octaves per second squared
+    ///   slide: 0.0,
+    ///   delta_slide: 0.0,
+    ///   // Fraction of the frequency and vibrations per second
+    ///   vibrato_depth: 0.0,
+    ///   vibrato_speed: 0.0,
+    ///   // Multiply the frequency once after the time in seconds, `0.0` disables it
+    ///   arpeggio_multiplier: 1.0,
+    ///   arpeggio_time: 0.0,
+    ///   // Fraction of the square wave that's high and its change per second
+    ///   duty: 0.5,
+    ///   duty_sweep: 0.0,
+    ///   volume: 0.5,
+    /// )
+    /// ```
+    ///
     /// # Arguments
     ///
-    /// * `path` - Asset path of the `.ogg` audio file, see [`Self`] for more information about asset loading and storing.
+    /// * `path` - Asset path of the `.ogg`, `.wav`, `.flac` or synthesized `.ron` audio file, see [`Self`] for more information about asset loading and storing.
     ///
     /// # Panics
     ///
@@ -172,6 +726,381 @@ impl Context {
             panning: None,
             loop_region: None,
             playback_region: None,
+            streaming: false,
+            track: None,
+            position: None,
+            pitch: None,
+            random_volume: None,
+            start_on: None,
+        }
+    }
+
+    /// Stream an audio clip, meant for long songs.
+    ///
+    /// Unlike [`Self::audio`] the audio file isn't decoded fully into memory, it's decoded in small parts while it's playing.
+    /// Embedded assets are streamed from the bytes in the binary and runtime assets are streamed from disk.
+    /// Check the [`AudioContext`] documentation for the playback options available.
+    ///
+    /// Streaming is not supported on the web, there the whole file is decoded when it's played for the first time.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Asset path of the `.ogg`, `.wav` or `.flac` audio file, see [`Self`] for more information about asset loading and storing.
+    ///
+    /// # Panics
+    ///
+    /// - When asset could not be found or opened.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chuot::Context;
+    ///
+    /// # struct Empty; impl Empty {
+    /// // In `Game::init` trait implementation
+    /// // ..
+    /// fn init(&mut self, ctx: Context) {
+    ///   // Stream a "theme.ogg" file again and again
+    ///   ctx.music("theme").with_loop().play();
+    /// }
+    /// # }
+    #[inline(always)]
+    #[must_use]
+    pub const fn music<'path>(&self, path: &'path str) -> AudioContext<'path, '_> {
+        AudioContext {
+            path,
+            ctx: self,
+            volume: None,
+            panning: None,
+            loop_region: None,
+            playback_region: None,
+            streaming: true,
+            track: None,
+            position: None,
+            pitch: None,
+            random_volume: None,
+            start_on: None,
         }
     }
+
+    /// Control a sound that's playing.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - Handle returned by [`AudioContext::play`].
+    ///
+    /// # Returns
+    ///
+    /// - A helper struct allowing you to stop, pause, seek and tween the sound.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chuot::{Context, context::audio::SoundHandle};
+    ///
+    /// # struct Empty { music: Option<SoundHandle> } impl Empty {
+    /// // In `Game::update` trait implementation
+    /// // ..
+    /// fn update(&mut self, ctx: Context) {
+    ///   if let Some(music) = self.music.take() {
+    ///     // Fade out the song in a second
+    ///     ctx.sound(music).stop(1.0);
+    ///   } else {
+    ///     self.music = Some(ctx.audio("song").with_loop().play());
+    ///   }
+    /// }
+    /// # }
+    #[inline(always)]
+    #[must_use]
+    pub const fn sound(&self, handle: SoundHandle) -> SoundContext<'_> {
+        SoundContext { ctx: self, handle }
+    }
+
+    /// Configure a named mixer track, creating it if it doesn't exist yet.
+    ///
+    /// Sounds are played on a track with [`AudioContext::on_track`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Unique name of the track, for example `"music"` or `"sfx"`.
+    ///
+    /// # Returns
+    ///
+    /// - A helper struct allowing you to change the volume and the effects of the track.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chuot::{Context, context::audio::FilterMode};
+    ///
+    /// # struct Empty { paused: bool } impl Empty {
+    /// // In `Game::update` trait implementation
+    /// // ..
+    /// fn update(&mut self, ctx: Context) {
+    ///   // Muffle the sound effects while the game is paused
+    ///   if self.paused {
+    ///     ctx.track("sfx").set_filter(FilterMode::LowPass, 800.0, 0.3);
+    ///   } else {
+    ///     ctx.track("sfx").remove_filter(0.3);
+    ///   }
+    ///
+    ///   ctx.audio("jump").on_track("sfx").play();
+    /// }
+    /// # }
+    #[inline(always)]
+    #[must_use]
+    pub const fn track<'ctx>(&'ctx self, name: &'ctx str) -> TrackContext<'ctx> {
+        TrackContext { ctx: self, name }
+    }
+
+    /// Change the volume of all sounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `volume` - Volume multiplication factor in the range `0.0..=1.0`.
+    /// * `duration` - Seconds it takes to smoothly reach the new volume, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_master_volume(&self, volume: f32, duration: f32) {
+        self.write(|ctx| {
+            ctx.master_volume = volume_to_decibels(volume);
+
+            if !ctx.master_muted {
+                ctx.audio_manager
+                    .main_track()
+                    .set_volume(ctx.master_volume, tween(duration));
+            }
+        });
+    }
+
+    /// Silence all sounds, or restore the master volume.
+    ///
+    /// # Arguments
+    ///
+    /// * `muted` - Whether to silence all sounds.
+    /// * `duration` - Seconds it takes to fade, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_master_muted(&self, muted: bool, duration: f32) {
+        self.write(|ctx| {
+            ctx.master_muted = muted;
+
+            let volume = if muted {
+                Decibels::SILENCE
+            } else {
+                ctx.master_volume
+            };
+            ctx.audio_manager
+                .main_track()
+                .set_volume(volume, tween(duration));
+        });
+    }
+
+    /// Set how sounds played with [`AudioContext::at`] are panned and faded by their distance to the main camera.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Falloff curve and maximum distance.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chuot::{
+    ///     Context,
+    ///     context::audio::{Falloff, SpatialSettings},
+    /// };
+    ///
+    /// # struct Empty; impl Empty {
+    /// // In `Game::init` trait implementation
+    /// // ..
+    /// fn init(&mut self, ctx: Context) {
+    ///   // Sounds further than 200 pixels away can't be heard
+    ///   ctx.set_spatial_audio_settings(
+    ///     SpatialSettings::default()
+    ///       .with_falloff(Falloff::Quadratic)
+    ///       .with_max_distance(200.0),
+    ///   );
+    /// }
+    /// # }
+    #[inline]
+    pub fn set_spatial_audio_settings(&self, settings: SpatialSettings) {
+        self.write(|ctx| ctx.spatial_settings = settings);
+    }
+
+    /// Whether all sounds are silenced with [`Self::set_master_muted`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when muted.
+    #[inline]
+    #[must_use]
+    pub fn is_master_muted(&self) -> bool {
+        self.read(|ctx| ctx.master_muted)
+    }
+
+    /// Whether sounds are heard on an audio device.
+    ///
+    /// When no audio device could be opened, all audio is mixed silently instead of panicking.
+    /// Sounds, music and clocks keep working as usual, so game logic depending on them doesn't need to check this.
+    ///
+    /// # Returns
+    ///
+    /// - `false` when audio is silently mixed without an audio device.
+    #[inline]
+    #[must_use]
+    pub fn audio_available(&self) -> bool {
+        self.read(|ctx| ctx.audio_manager.is_available())
+    }
+}
+
+/// Configure a named mixer track.
+///
+/// Every track has a filter, a reverb and a delay effect, in that order, which are all disabled by default.
+///
+/// Used by [`Context::track`].
+pub struct TrackContext<'ctx> {
+    /// Reference to the context the track is mixed in.
+    pub(crate) ctx: &'ctx Context,
+    /// Name of the track.
+    pub(crate) name: &'ctx str,
+}
+
+impl TrackContext<'_> {
+    /// Change the volume of all sounds on the track.
+    ///
+    /// # Arguments
+    ///
+    /// * `volume` - Volume multiplication factor in the range `0.0..=1.0`.
+    /// * `duration` - Seconds it takes to smoothly reach the new volume, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_volume(&self, volume: f32, duration: f32) {
+        self.ctx.write(|ctx| {
+            ctx.track_mut(self.name)
+                .set_volume(volume_to_decibels(volume), tween(duration));
+        });
+    }
+
+    /// Silence all sounds on the track, or restore its volume.
+    ///
+    /// # Arguments
+    ///
+    /// * `muted` - Whether to silence the track.
+    /// * `duration` - Seconds it takes to fade, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_muted(&self, muted: bool, duration: f32) {
+        self.ctx.write(|ctx| {
+            ctx.track_mut(self.name).set_muted(muted, tween(duration));
+        });
+    }
+
+    /// Whether the track is silenced with [`Self::set_muted`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when muted.
+    #[inline]
+    #[must_use]
+    pub fn is_muted(&self) -> bool {
+        self.ctx
+            .read(|ctx| ctx.tracks.get(self.name).is_some_and(MixerTrack::is_muted))
+    }
+
+    /// Pause all sounds on the track.
+    ///
+    /// # Arguments
+    ///
+    /// * `fade_out` - Seconds it takes for the volume to fade to silence, `0.0` pauses immediately.
+    #[inline]
+    pub fn pause(&self, fade_out: f32) {
+        self.ctx.write(|ctx| {
+            ctx.track_mut(self.name).handle.pause(tween(fade_out));
+        });
+    }
+
+    /// Resume all sounds on the track after it's paused with [`Self::pause`].
+    ///
+    /// # Arguments
+    ///
+    /// * `fade_in` - Seconds it takes for the volume to fade back in, `0.0` resumes immediately.
+    #[inline]
+    pub fn resume(&self, fade_in: f32) {
+        self.ctx.write(|ctx| {
+            ctx.track_mut(self.name).handle.resume(tween(fade_in));
+        });
+    }
+
+    /// Filter the frequencies of all sounds on the track, for example to muffle them.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Which frequencies relative to the cutoff are removed.
+    /// * `cutoff` - Frequency in hertz at which the filter starts.
+    /// * `duration` - Seconds it takes to smoothly reach the new cutoff, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_filter(&self, mode: FilterMode, cutoff: f32, duration: f32) {
+        self.ctx.write(|ctx| {
+            ctx.track_mut(self.name)
+                .set_filter(mode, f64::from(cutoff), tween(duration));
+        });
+    }
+
+    /// Stop filtering the sounds on the track.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - Seconds it takes to smoothly remove the filter, `0.0` removes it immediately.
+    #[inline]
+    pub fn remove_filter(&self, duration: f32) {
+        self.ctx.write(|ctx| {
+            ctx.track_mut(self.name).remove_filter(tween(duration));
+        });
+    }
+
+    /// Add reverb to all sounds on the track.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix` - How much of the reverb is heard in the range `0.0..=1.0`, `0.0` disables it.
+    /// * `duration` - Seconds it takes to smoothly reach the new mix, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_reverb(&self, mix: f32, duration: f32) {
+        self.ctx.write(|ctx| {
+            ctx.track_mut(self.name)
+                .set_reverb(Mix(mix), tween(duration));
+        });
+    }
+
+    /// Add a repeating echo to all sounds on the track.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix` - How much of the echo is heard in the range `0.0..=1.0`, `0.0` disables it.
+    /// * `duration` - Seconds it takes to smoothly reach the new mix, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_delay(&self, mix: f32, duration: f32) {
+        self.ctx.write(|ctx| {
+            ctx.track_mut(self.name)
+                .set_delay(Mix(mix), tween(duration));
+        });
+    }
+}
+
+/// Convert a volume multiplication factor to decibels.
+pub(crate) fn volume_to_decibels(volume: f32) -> Decibels {
+    if volume <= 0.0 {
+        return Decibels::SILENCE;
+    }
+
+    Decibels((20.0 * volume.log10()).max(Decibels::SILENCE.0))
+}
+
+/// Convert a panning in the range `0.0..=1.0` to the range `-1.0..=1.0` used by the audio manager.
+pub(crate) fn panning_from_balance(panning: f32) -> Panning {
+    Panning(panning.mul_add(2.0, -1.0))
+}
+
+/// Create a linear tween lasting a duration in seconds.
+pub(crate) fn tween(duration: f32) -> Tween {
+    Tween {
+        duration: Duration::from_secs_f32(duration.max(0.0)),
+        ..Default::default()
+    }
 }
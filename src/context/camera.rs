@@ -1,15 +1,18 @@
 //! Zero-cost abstraction types for configuring the camera systems.
 
-use crate::Context;
+use crate::{
+    Context,
+    camera::{Camera, CameraId},
+};
 
 /// Specify how the text should be drawn.
 ///
-/// Used by [`Context::main_camera`](crate::Context::main_camera) and [`Context::ui_camera`](crate::Context::ui_camera).
+/// Used by [`Context::main_camera`](crate::Context::main_camera), [`Context::ui_camera`](crate::Context::ui_camera) and [`Context::camera`](crate::Context::camera).
 pub struct CameraContext<'ctx> {
     /// Reference to the context the text will draw in when finished.
     pub(crate) ctx: &'ctx Context,
-    /// Whether to use the UI camera for positioning the text, `false` uses the regular game camera.
-    pub(crate) is_ui_camera: bool,
+    /// Which camera to configure.
+    pub(crate) id: CameraId<'ctx>,
 }
 
 impl CameraContext<'_> {
@@ -25,7 +28,7 @@ impl CameraContext<'_> {
     #[inline]
     pub fn shake(&self, duration: f32, amplitude: f32, frequency: f32) {
         self.ctx.write(|ctx| {
-            ctx.camera_mut(self.is_ui_camera)
+            ctx.camera_by_id_mut(self.id)
                 .shake(duration, amplitude, frequency);
         });
     }
@@ -38,7 +41,7 @@ impl CameraContext<'_> {
     #[inline]
     pub fn follow_x(&self, x: f32) {
         self.ctx.write(|ctx| {
-            ctx.camera_mut(self.is_ui_camera).set_target_x(x);
+            ctx.camera_by_id_mut(self.id).set_follow_x(x);
         });
     }
 
@@ -50,7 +53,7 @@ impl CameraContext<'_> {
     #[inline]
     pub fn follow_y(&self, y: f32) {
         self.ctx.write(|ctx| {
-            ctx.camera_mut(self.is_ui_camera).set_target_y(y);
+            ctx.camera_by_id_mut(self.id).set_follow_y(y);
         });
     }
 
@@ -64,18 +67,93 @@ impl CameraContext<'_> {
         // Reduce compilation times
         fn inner(this: &CameraContext, (x, y): (f32, f32)) {
             this.ctx.write(|ctx| {
-                let camera = ctx.camera_mut(this.is_ui_camera);
-                camera.set_target_x(x);
-                camera.set_target_y(y);
+                let camera = ctx.camera_by_id_mut(this.id);
+                camera.set_follow_x(x);
+                camera.set_follow_y(y);
             });
         }
 
         inner(self, target.into());
     }
 
+    /// Forget the previous followed position, so the look-ahead doesn't see a jump in position as movement.
+    ///
+    /// Call this after teleporting or respawning the followed item.
+    #[inline]
+    pub fn reset_follow(&self) {
+        self.ctx.write(|ctx| {
+            ctx.camera_by_id_mut(self.id).reset_follow();
+        });
+    }
+
+    /// Set the size of the deadzone box in which the followed item can move without moving the camera.
+    ///
+    /// The camera only starts moving when the position passed to [`Self::follow`] leaves the box.
+    /// Defaults to `(0.0, 0.0)`, which disables the deadzone.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Horizontal size of the deadzone box in world pixels.
+    /// * `height` - Vertical size of the deadzone box in world pixels.
+    #[inline]
+    pub fn set_deadzone(&self, width: f32, height: f32) {
+        self.ctx.write(|ctx| {
+            ctx.camera_by_id_mut(self.id).set_deadzone(width, height);
+        });
+    }
+
+    /// Make the camera look ahead in the direction the followed item is moving.
+    ///
+    /// The velocity is calculated from the positions passed to [`Self::follow`] every update tick.
+    /// Call [`Self::reset_follow`] after teleporting the followed item to prevent the camera from looking ahead in the direction of the jump.
+    /// Defaults to `(0.0, 0.0)`, which disables the look-ahead.
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - How many seconds of the velocity to look ahead.
+    /// * `max_distance` - Maximum distance in world pixels the camera looks ahead.
+    #[inline]
+    pub fn set_look_ahead(&self, seconds: f32, max_distance: f32) {
+        self.ctx.write(|ctx| {
+            ctx.camera_by_id_mut(self.id)
+                .set_look_ahead(seconds, max_distance);
+        });
+    }
+
+    /// Clamp the camera so its visible area stays inside the world bounds.
+    ///
+    /// When the bounds are smaller than the visible area the camera will be centered in the bounds.
+    /// The rotation of the camera is not taken into account.
+    ///
+    /// # Arguments
+    ///
+    /// * `(min_x, min_y)` - Tuple of the top left corner of the bounds in world space.
+    /// * `(max_x, max_y)` - Tuple of the bottom right corner of the bounds in world space.
+    #[inline]
+    pub fn set_bounds(&self, min: impl Into<(f32, f32)>, max: impl Into<(f32, f32)>) {
+        // Reduce compilation times
+        fn inner(this: &CameraContext, min: (f32, f32), max: (f32, f32)) {
+            this.ctx.write(|ctx| {
+                ctx.camera_by_id_mut(this.id)
+                    .set_bounds(Some((min.into(), max.into())));
+            });
+        }
+
+        inner(self, min.into(), max.into());
+    }
+
+    /// Remove the bounds set with [`Self::set_bounds`], allowing the camera to move freely.
+    #[inline]
+    pub fn clear_bounds(&self) {
+        self.ctx.write(|ctx| {
+            ctx.camera_by_id_mut(self.id).set_bounds(None);
+        });
+    }
+
     /// Get the relative position if the mouse is inside the viewport frame.
     ///
     /// This is `Some(..`) if the mouse is inside the viewport frame, not the entire window.
+    /// The position is transformed by the position, zoom and rotation of the camera, so it can be compared directly with positions in world space.
     /// The value of the coordinates corresponds to the pixel, when the frame is scaled this also encodes the subpixel in the fractional part.
     ///
     /// # Returns
@@ -87,9 +165,8 @@ impl CameraContext<'_> {
     pub fn mouse(&self) -> Option<(f32, f32)> {
         self.ctx.read(|ctx| {
             ctx.input.mouse().map(|(mouse_x, mouse_y)| {
-                let camera = ctx.camera(self.is_ui_camera);
-
-                (mouse_x - camera.offset_x(), mouse_y - camera.offset_y())
+                ctx.camera_by_id(self.id)
+                    .inverse_transform_point(mouse_x, mouse_y)
             })
         })
     }
@@ -107,10 +184,10 @@ impl CameraContext<'_> {
     #[must_use]
     pub fn mouse_x(&self) -> Option<f32> {
         self.ctx.read(|ctx| {
-            ctx.input.mouse().map(|(mouse_x, _)| {
-                let camera = ctx.camera(self.is_ui_camera);
-
-                mouse_x - camera.offset_x()
+            ctx.input.mouse().map(|(mouse_x, mouse_y)| {
+                ctx.camera_by_id(self.id)
+                    .inverse_transform_point(mouse_x, mouse_y)
+                    .0
             })
         })
     }
@@ -128,9 +205,11 @@ impl CameraContext<'_> {
     #[must_use]
     pub fn mouse_y(&self) -> Option<f32> {
         self.ctx.read(|ctx| {
-            ctx.input
-                .mouse()
-                .map(|(_, mouse_y)| mouse_y - ctx.camera(self.is_ui_camera).offset_y())
+            ctx.input.mouse().map(|(mouse_x, mouse_y)| {
+                ctx.camera_by_id(self.id)
+                    .inverse_transform_point(mouse_x, mouse_y)
+                    .1
+            })
         })
     }
 
@@ -142,7 +221,7 @@ impl CameraContext<'_> {
     #[inline]
     pub fn set_lerp_x(&self, lerp_x: f32) {
         self.ctx.write(|ctx| {
-            ctx.camera_mut(self.is_ui_camera).set_lerp_x(lerp_x);
+            ctx.camera_by_id_mut(self.id).set_lerp_x(lerp_x);
         });
     }
 
@@ -154,7 +233,7 @@ impl CameraContext<'_> {
     #[inline]
     pub fn set_lerp_y(&self, lerp_y: f32) {
         self.ctx.write(|ctx| {
-            ctx.camera_mut(self.is_ui_camera).set_lerp_y(lerp_y);
+            ctx.camera_by_id_mut(self.id).set_lerp_y(lerp_y);
         });
     }
 
@@ -166,22 +245,180 @@ impl CameraContext<'_> {
     #[inline]
     pub fn set_lerp(&self, lerp: f32) {
         self.ctx.write(|ctx| {
-            let camera = ctx.camera_mut(self.is_ui_camera);
+            let camera = ctx.camera_by_id_mut(self.id);
             camera.set_lerp_x(lerp);
             camera.set_lerp_y(lerp);
         });
     }
 
-    /// Center the camera at the middle of the screen.
+    /// Set the zoom factor of the camera immediately.
+    ///
+    /// The zoom is applied around the center of the camera, which is the middle of the screen for the main camera.
+    ///
+    /// # Arguments
+    ///
+    /// * `zoom` - Zoom factor, `1.0` is no zoom, `2.0` draws everything twice as large, must be larger than `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// - When `zoom` is not larger than `0.0`.
+    #[inline]
+    pub fn set_zoom(&self, zoom: f32) {
+        assert!(zoom > 0.0, "Zoom factor must be positive");
+
+        self.ctx.write(|ctx| {
+            ctx.camera_by_id_mut(self.id).set_zoom(zoom);
+        });
+    }
+
+    /// Make the camera zoom towards the zoom factor smoothly.
+    ///
+    /// The speed is configured with [`Self::set_lerp_zoom`].
+    ///
+    /// # Arguments
+    ///
+    /// * `zoom` - Target zoom factor, `1.0` is no zoom, `2.0` draws everything twice as large, must be larger than `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// - When `zoom` is not larger than `0.0`.
+    #[inline]
+    pub fn set_zoom_smoothed(&self, zoom: f32) {
+        assert!(zoom > 0.0, "Zoom factor must be positive");
+
+        self.ctx.write(|ctx| {
+            ctx.camera_by_id_mut(self.id).set_target_zoom(zoom);
+        });
+    }
+
+    /// Get the current zoom factor of the camera.
+    ///
+    /// # Returns
+    ///
+    /// - Zoom factor, `1.0` is no zoom.
+    #[inline]
+    #[must_use]
+    pub fn zoom(&self) -> f32 {
+        self.ctx.read(|ctx| ctx.camera_by_id(self.id).zoom())
+    }
+
+    /// Set the rotation of the camera immediately.
+    ///
+    /// The rotation is applied around the center of the camera, which is the middle of the screen for the main camera.
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation` - Rotation in radians.
+    #[inline]
+    pub fn set_rotation(&self, rotation: f32) {
+        self.ctx.write(|ctx| {
+            ctx.camera_by_id_mut(self.id).set_rotation(rotation);
+        });
+    }
+
+    /// Make the camera rotate towards the rotation smoothly.
+    ///
+    /// The speed is configured with [`Self::set_lerp_rotation`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation` - Target rotation in radians.
+    #[inline]
+    pub fn set_rotation_smoothed(&self, rotation: f32) {
+        self.ctx.write(|ctx| {
+            ctx.camera_by_id_mut(self.id).set_target_rotation(rotation);
+        });
+    }
+
+    /// Get the current rotation of the camera.
+    ///
+    /// # Returns
+    ///
+    /// - Rotation in radians.
+    #[inline]
+    #[must_use]
+    pub fn rotation(&self) -> f32 {
+        self.ctx.read(|ctx| ctx.camera_by_id(self.id).rotation())
+    }
+
+    /// Set the zoom linear interpolation factor applied every update tick.
+    ///
+    /// # Arguments
+    ///
+    /// * `lerp_zoom` - Zoom linear interpolation applied to the camera every update tick.
+    #[inline]
+    pub fn set_lerp_zoom(&self, lerp_zoom: f32) {
+        self.ctx.write(|ctx| {
+            ctx.camera_by_id_mut(self.id).set_lerp_zoom(lerp_zoom);
+        });
+    }
+
+    /// Set the rotation linear interpolation factor applied every update tick.
+    ///
+    /// # Arguments
+    ///
+    /// * `lerp_rotation` - Rotation linear interpolation applied to the camera every update tick.
+    #[inline]
+    pub fn set_lerp_rotation(&self, lerp_rotation: f32) {
+        self.ctx.write(|ctx| {
+            ctx.camera_by_id_mut(self.id)
+                .set_lerp_rotation(lerp_rotation);
+        });
+    }
+
+    /// Set the rectangle on the buffer the camera renders to when drawing with [`Context::render_viewports`].
+    ///
+    /// This also centers the camera in the viewport.
+    ///
+    /// # Arguments
+    ///
+    /// * `(x, y)` - Tuple of the top left corner of the viewport on the buffer in pixels.
+    /// * `(width, height)` - Tuple of the size of the viewport in pixels.
+    #[inline]
+    pub fn set_viewport(&self, position: impl Into<(f32, f32)>, size: impl Into<(f32, f32)>) {
+        // Reduce compilation times
+        fn inner(this: &CameraContext, (x, y): (f32, f32), (width, height): (f32, f32)) {
+            this.ctx.write(|ctx| {
+                let buffer_width = ctx.config.buffer_width;
+                let buffer_height = ctx.config.buffer_height;
+
+                ctx.camera_by_id_mut(this.id).set_viewport(
+                    Some((x, y, width, height)),
+                    buffer_width,
+                    buffer_height,
+                );
+            });
+        }
+
+        inner(self, position.into(), size.into());
+    }
+
+    /// Remove the viewport set with [`Self::set_viewport`], making the camera render to the whole buffer again.
+    ///
+    /// Cameras without a viewport are skipped by [`Context::render_viewports`].
+    #[inline]
+    pub fn clear_viewport(&self) {
+        self.ctx.write(|ctx| {
+            let buffer_width = ctx.config.buffer_width;
+            let buffer_height = ctx.config.buffer_height;
+
+            ctx.camera_by_id_mut(self.id)
+                .set_viewport(None, buffer_width, buffer_height);
+        });
+    }
+
+    /// Center the camera at the middle of the screen, or the middle of the viewport if set.
     ///
-    /// This is the default for the main camera.
+    /// This is the default for the main camera and cameras created with [`Context::camera`].
     #[inline]
     pub fn set_center(&self) {
         self.ctx.write(|ctx| {
-            let width = ctx.config.buffer_width;
-            let height = ctx.config.buffer_height;
+            let buffer_width = ctx.config.buffer_width;
+            let buffer_height = ctx.config.buffer_height;
 
-            ctx.camera_mut(self.is_ui_camera).center(width, height);
+            let camera = ctx.camera_by_id_mut(self.id);
+            let (width, height) = camera.viewport_size(buffer_width, buffer_height);
+            camera.center(width, height);
         });
     }
 
@@ -191,7 +428,7 @@ impl CameraContext<'_> {
     #[inline]
     pub fn set_top_left(&self) {
         self.ctx.write(|ctx| {
-            ctx.camera_mut(self.is_ui_camera).top_left();
+            ctx.camera_by_id_mut(self.id).top_left();
         });
     }
 }
@@ -217,7 +454,7 @@ impl Context {
     pub const fn main_camera(&self) -> CameraContext<'_> {
         CameraContext {
             ctx: self,
-            is_ui_camera: false,
+            id: CameraId::Main,
         }
     }
 
@@ -234,7 +471,120 @@ impl Context {
     pub const fn ui_camera(&self) -> CameraContext<'_> {
         CameraContext {
             ctx: self,
-            is_ui_camera: true,
+            id: CameraId::Ui,
         }
     }
+
+    /// Configure a user created camera, creating it if it doesn't exist yet.
+    ///
+    /// Named cameras are used for split-screen games, each camera can be given its own viewport on the buffer with [`CameraContext::set_viewport`].
+    /// While rendering the viewports with [`Context::render_viewports`] the camera replaces the main camera.
+    /// A newly created camera is centered.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Unique name of the camera.
+    ///
+    /// # Returns
+    ///
+    /// - A helper struct allowing you to configure the camera.
+    #[inline]
+    #[must_use]
+    pub fn camera<'ctx>(&'ctx self, name: &'ctx str) -> CameraContext<'ctx> {
+        self.write(|ctx| {
+            if !ctx
+                .cameras
+                .iter()
+                .any(|(camera_name, _)| camera_name == name)
+            {
+                // Create a new centered camera
+                let mut camera = Camera::default();
+                camera.center(ctx.config.buffer_width, ctx.config.buffer_height);
+
+                ctx.cameras.push((name.to_owned(), camera));
+            }
+        });
+
+        CameraContext {
+            ctx: self,
+            id: CameraId::Named(name),
+        }
+    }
+
+    /// Remove a camera created with [`Context::camera`].
+    ///
+    /// Does nothing when the camera doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Unique name of the camera.
+    ///
+    /// # Panics
+    ///
+    /// - When called inside [`Context::render_viewports`].
+    #[inline]
+    pub fn remove_camera(&self, name: &str) {
+        self.write(|ctx| {
+            assert!(
+                ctx.active_camera.is_none(),
+                "Cameras can't be removed while rendering the viewports"
+            );
+
+            ctx.cameras.retain(|(camera_name, _)| camera_name != name);
+        });
+    }
+
+    /// Call the render function once for every camera with a viewport, used for split-screen games.
+    ///
+    /// Every call everything drawn with the main camera is drawn with the camera of the viewport instead, and is clipped to the viewport.
+    /// Items drawn with the UI camera are also clipped to the viewport, but are not moved.
+    /// Cameras are rendered in the order they have been created in.
+    ///
+    /// # Arguments
+    ///
+    /// * `render` - Function called for every viewport with the name of the camera, draw the world inside of it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn call(ctx: chuot::Context) {
+    /// ctx.camera("left").set_viewport((0.0, 0.0), (160.0, 240.0));
+    /// ctx.camera("right").set_viewport((160.0, 0.0), (160.0, 240.0));
+    ///
+    /// ctx.render_viewports(|_camera| {
+    ///     ctx.sprite("level").draw();
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    pub fn render_viewports(&self, mut render: impl FnMut(&str)) {
+        // Get the cameras to render first so the render function can access the context
+        let cameras = self.read(|ctx| {
+            ctx.cameras
+                .iter()
+                .enumerate()
+                .filter_map(|(index, (name, camera))| {
+                    camera
+                        .viewport()
+                        .map(|viewport| (index, name.clone(), viewport))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        for (index, name, viewport) in cameras {
+            // Replace the main camera and clip to the viewport
+            self.write(|ctx| {
+                ctx.active_camera = Some(index);
+                ctx.graphics.set_scissor(Some(viewport));
+            });
+
+            render(&name);
+        }
+
+        // Restore the main camera
+        self.write(|ctx| {
+            ctx.active_camera = None;
+            ctx.graphics.set_scissor(None);
+        });
+    }
 }
@@ -0,0 +1,229 @@
+//! Zero-cost abstraction types for synchronizing to the musical beat.
+
+use kira::Tween;
+
+use crate::{Context, clock::BeatClock, context::audio::tween};
+
+/// Configure and query a named musical clock.
+///
+/// The clock ticks on the audio thread, so it doesn't drift from the music independent of the frame rate.
+/// Sounds can be started exactly on a beat with [`crate::context::audio::AudioContext::on_next_beat`] and [`crate::context::audio::AudioContext::on_next_bar`].
+///
+/// Used by [`Context::clock`].
+pub struct ClockContext<'ctx> {
+    /// Reference to the context the clock is ticking in.
+    pub(crate) ctx: &'ctx Context,
+    /// Name of the clock.
+    pub(crate) name: &'ctx str,
+}
+
+impl ClockContext<'_> {
+    /// Start ticking, creating the clock if it doesn't exist yet.
+    ///
+    /// Resumes a paused clock with the new tempo.
+    ///
+    /// # Arguments
+    ///
+    /// * `bpm` - Tempo in beats per minute.
+    /// * `beats_per_bar` - Amount of beats in a single bar, `4` for most music.
+    ///
+    /// # Panics
+    ///
+    /// - When the maximum amount of clocks is reached.
+    #[inline]
+    pub fn start(&self, bpm: f32, beats_per_bar: u32) {
+        self.ctx.write(|ctx| {
+            if let Some(clock) = ctx.clocks.get_mut(self.name) {
+                // Change the existing clock
+                clock.set_bpm(bpm, Tween::default());
+                clock.set_beats_per_bar(beats_per_bar);
+                clock.start();
+            } else {
+                // Create a new clock
+                let mut clock = BeatClock::new(&mut ctx.audio_manager, bpm, beats_per_bar);
+                clock.start();
+
+                ctx.clocks.insert(self.name.to_owned(), clock);
+            }
+        });
+    }
+
+    /// Change the tempo of the clock.
+    ///
+    /// Does nothing when the clock doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `bpm` - Tempo in beats per minute.
+    /// * `duration` - Seconds it takes to smoothly reach the new tempo, `0.0` changes it immediately.
+    #[inline]
+    pub fn set_bpm(&self, bpm: f32, duration: f32) {
+        self.ctx.write(|ctx| {
+            if let Some(clock) = ctx.clocks.get_mut(self.name) {
+                clock.set_bpm(bpm, tween(duration));
+            }
+        });
+    }
+
+    /// Pause ticking, resume with [`Self::start`].
+    ///
+    /// Sounds waiting for a beat wait until the clock is resumed.
+    #[inline]
+    pub fn pause(&self) {
+        self.ctx.write(|ctx| {
+            if let Some(clock) = ctx.clocks.get_mut(self.name) {
+                clock.pause();
+            }
+        });
+    }
+
+    /// Pause ticking and reset the clock to the first beat.
+    #[inline]
+    pub fn stop(&self) {
+        self.ctx.write(|ctx| {
+            if let Some(clock) = ctx.clocks.get_mut(self.name) {
+                clock.stop();
+            }
+        });
+    }
+
+    /// Whether the clock is ticking.
+    ///
+    /// # Returns
+    ///
+    /// - `false` when the clock is paused, stopped or doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn is_ticking(&self) -> bool {
+        self.ctx
+            .read(|ctx| ctx.clocks.get(self.name).is_some_and(BeatClock::is_ticking))
+    }
+
+    /// Amount of beats since the clock started.
+    ///
+    /// # Returns
+    ///
+    /// - Beat counting from `0`, `0` when the clock doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn beat(&self) -> u64 {
+        self.ctx
+            .read(|ctx| ctx.clocks.get(self.name).map_or(0, BeatClock::beat))
+    }
+
+    /// Progress towards the next beat, for animating things to the music.
+    ///
+    /// # Returns
+    ///
+    /// - Fraction in the range `0.0..1.0`, `0.0` when the clock doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn beat_fraction(&self) -> f32 {
+        self.ctx.read(|ctx| {
+            ctx.clocks
+                .get(self.name)
+                .map_or(0.0, BeatClock::beat_fraction)
+        })
+    }
+
+    /// Amount of bars since the clock started.
+    ///
+    /// # Returns
+    ///
+    /// - Bar counting from `0`, `0` when the clock doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn bar(&self) -> u64 {
+        self.ctx.read(|ctx| {
+            ctx.clocks
+                .get(self.name)
+                .map_or(0, |clock| clock.beat() / u64::from(clock.beats_per_bar()))
+        })
+    }
+
+    /// Beat in the current bar.
+    ///
+    /// # Returns
+    ///
+    /// - Beat counting from `0` up to the beats per bar, `0` when the clock doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn beat_in_bar(&self) -> u32 {
+        self.ctx.read(|ctx| {
+            ctx.clocks.get(self.name).map_or(0, |clock| {
+                (clock.beat() % u64::from(clock.beats_per_bar())) as u32
+            })
+        })
+    }
+
+    /// Whether a new beat started since the previous update tick, for spawning things on the beat.
+    ///
+    /// # Returns
+    ///
+    /// - `true` once for every beat, `false` when the clock doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn is_new_beat(&self) -> bool {
+        self.ctx.read(|ctx| {
+            ctx.clocks
+                .get(self.name)
+                .is_some_and(BeatClock::is_new_beat)
+        })
+    }
+
+    /// Whether a new bar started since the previous update tick.
+    ///
+    /// # Returns
+    ///
+    /// - `true` once for every bar, `false` when the clock doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn is_new_bar(&self) -> bool {
+        self.is_new_beat() && self.beat_in_bar() == 0
+    }
+}
+
+/// Clock methods.
+impl Context {
+    /// Configure and query a named musical clock for synchronizing to the beat.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Unique name of the clock, for example `"music"`.
+    ///
+    /// # Returns
+    ///
+    /// - A helper struct allowing you to start the clock and to query the beat.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chuot::Context;
+    ///
+    /// # struct Empty; impl Empty {
+    /// // In `Game::init` trait implementation
+    /// // ..
+    /// fn init(&mut self, ctx: Context) {
+    ///   // Start the song and the clock at the same moment
+    ///   ctx.clock("song").start(120.0, 4);
+    ///   ctx.music("song").with_loop().play();
+    /// }
+    ///
+    /// // In `Game::update` trait implementation
+    /// // ..
+    /// fn update(&mut self, ctx: Context) {
+    ///   // Spawn an enemy every bar
+    ///   if ctx.clock("song").is_new_bar() {
+    ///     // ..
+    ///   }
+    ///
+    ///   // Play a sound exactly on the next beat
+    ///   ctx.audio("clap").on_next_beat("song").play();
+    /// }
+    /// # }
+    #[inline(always)]
+    #[must_use]
+    pub const fn clock<'ctx>(&'ctx self, name: &'ctx str) -> ClockContext<'ctx> {
+        ClockContext { ctx: self, name }
+    }
+}
@@ -91,13 +91,17 @@ impl<'font, 'ctx> FontContext<'font, 'ctx> {
     pub const fn text<'text>(
         self,
         text: &'text str,
-    ) -> TextContext<'font, 'text, 'ctx, Empty, Empty, MainCamera> {
+    ) -> TextContext<'font, 'text, 'ctx, Empty, Empty, Empty, Empty, Empty, Empty, MainCamera> {
         TextContext {
             font: self.font,
             ctx: self.ctx,
             text,
             translation: Empty,
             previous_translation: Empty,
+            rotation: Empty,
+            scaling: Empty,
+            pivot: Empty,
+            shader: Empty,
             phantom: PhantomData,
         }
     }
@@ -2,19 +2,25 @@
 
 pub mod audio;
 pub mod camera;
+pub mod clock;
 pub(crate) mod extensions;
 pub mod font;
 #[doc(hidden)]
 pub mod load;
+pub mod music;
 pub mod sprite;
 pub mod text;
+pub mod ui;
 
 use std::{cell::RefCell, rc::Rc, sync::Arc};
 
-use gilrs::GamepadId;
-use kira::{AudioManager, AudioManagerSettings, DefaultBackend};
+use hashbrown::HashMap;
+use kira::{
+    Decibels, PlaySoundError, Tween,
+    sound::{PlaybackState, SoundData, static_sound::StaticSoundData},
+};
 use smallvec::SmallVec;
-use winit::window::{Fullscreen, Window};
+use winit::window::{CursorGrabMode, Fullscreen, Window};
 
 use crate::{
     GamepadAxis, GamepadButton, KeyCode, MouseButton,
@@ -23,10 +29,17 @@ use crate::{
         loadable::{Loadable, audio::Audio, font::Font, shader::Shader, sprite::Sprite},
         source::AssetSource,
     },
-    camera::Camera,
+    audio_backend::AudioBackend,
+    camera::{Camera, CameraId},
+    clock::BeatClock,
     config::Config,
+    context::audio::{PlayingSound, SoundHandle, panning_from_balance, volume_to_decibels},
     graphics::Graphics,
-    input::Input,
+    input::{Binding, GamepadId, Input, InputBindings, InputRecording, Stick, StickSettings},
+    mixer::MixerTrack,
+    music::MusicPlayer,
+    spatial::{Emitter, SpatialSettings},
+    ui::Ui,
 };
 
 /// Context containing most functionality for interfacing with the game engine.
@@ -41,7 +54,8 @@ use crate::{
 /// | --- | --- |
 /// | `ctx.sprite("player")` | `assets/player.png` & `assets/player.toml` (optional) |
 /// | `ctx.sprite("gui.widgets.button")` | `assets/gui/widgets/button.png` & `assets/gui/widgets/button.toml` (optional) |
-/// | `ctx.audio("song")` | `assets/song.ogg` |
+/// | `ctx.audio("song")` | `assets/song.ogg`, `assets/song.wav` or `assets/song.flac` |
+/// | `ctx.audio("jump")` | `assets/jump.ron` when no audio file exists, a sound group or synthesized as a retro sound effect |
 /// | `ctx.font("font")` | `assets/font.png` & `assets/font.ron` |
 ///
 /// It's assumed for this table that [`crate::load_assets`] in [`crate::Game`] is called without any arguments or with `chuot::load_assets!("assets/")`.
@@ -166,6 +180,86 @@ impl Context {
         self.write(|ctx| ctx.window.set_cursor_visible(visible));
     }
 
+    /// Replace the OS cursor with a sprite.
+    ///
+    /// The sprite is drawn on top of everything with the UI camera at the mouse position, so it's rendered at the resolution of the buffer.
+    /// The pivot of the sprite is used as the hotspot of the cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Asset path of the sprite, see [`Self`] for more information about asset loading and storing.
+    ///
+    /// # Panics
+    ///
+    /// - When asset failed loading.
+    #[inline]
+    pub fn set_cursor_sprite(&self, path: impl Into<String>) {
+        // Reduce compilation times
+        fn inner(this: &Context, path: String) {
+            this.write(|ctx| {
+                ctx.window.set_cursor_visible(false);
+                ctx.cursor_sprite = Some(path);
+            });
+        }
+
+        inner(self, path.into());
+    }
+
+    /// Stop drawing the sprite set with [`Self::set_cursor_sprite`] and show the OS cursor again.
+    #[inline]
+    pub fn remove_cursor_sprite(&self) {
+        self.write(|ctx| {
+            ctx.window.set_cursor_visible(true);
+            ctx.cursor_sprite = None;
+        });
+    }
+
+    /// Lock the cursor inside the window, or to its position.
+    ///
+    /// Not every mode is supported on every platform, when the mode isn't supported the other mode is tried.
+    /// Check the returned mode, because a confined cursor still moves while a locked cursor doesn't.
+    /// On the web a locked cursor is only allowed after the player clicked on the canvas.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - [`CursorGrabMode::Confined`] to keep the cursor inside the window, [`CursorGrabMode::Locked`] to keep it at the same position, use [`Self::mouse_motion`] to read the movement, or [`CursorGrabMode::None`] to release it.
+    ///
+    /// # Returns
+    ///
+    /// - `None` when the cursor could not be grabbed in either mode.
+    /// - `Some(..)` with the mode that's applied, which is the other mode when `mode` isn't supported.
+    #[inline]
+    #[must_use]
+    pub fn set_cursor_grab(&self, mode: CursorGrabMode) -> Option<CursorGrabMode> {
+        self.read(|ctx| {
+            // Try the other mode when the platform doesn't support it
+            let fallback = match mode {
+                CursorGrabMode::Confined => CursorGrabMode::Locked,
+                CursorGrabMode::Locked => CursorGrabMode::Confined,
+                CursorGrabMode::None => CursorGrabMode::None,
+            };
+
+            ctx.window
+                .set_cursor_grab(mode)
+                .map(|()| mode)
+                .or_else(|_| ctx.window.set_cursor_grab(fallback).map(|()| fallback))
+                .ok()
+        })
+    }
+
+    /// Allow text to be typed with an input method editor, for languages that can't be typed directly with the keys.
+    ///
+    /// The typed text is received with [`Self::text_input`].
+    /// Should only be enabled when the player is typing text, because the input method editor can intercept key presses.
+    ///
+    /// # Arguments
+    ///
+    /// * `allowed` - `true` to allow the input method editor, `false` to disable it.
+    #[inline]
+    pub fn set_ime_allowed(&self, allowed: bool) {
+        self.write(|ctx| ctx.window.set_ime_allowed(allowed));
+    }
+
     /// Toggle fullscreen mode.
     ///
     /// Uses a borderless fullscreen mode, not exclusive.
@@ -235,6 +329,35 @@ impl Context {
         self.read(|ctx| ctx.frames_per_second)
     }
 
+    /// Get the amount of sprite and text glyph instances drawn in the previous frame.
+    ///
+    /// Instances outside of the visible area are culled and not counted, see [`Self::culled_instances`].
+    /// Useful for profiling.
+    ///
+    /// # Returns
+    ///
+    /// - Amount of instances send to the GPU in the previous frame.
+    #[inline]
+    #[must_use]
+    pub fn drawn_instances(&self) -> usize {
+        self.read(|ctx| ctx.graphics.previous_instance_counts.0)
+    }
+
+    /// Get the amount of sprite and text glyph instances skipped in the previous frame because they were outside of the visible area.
+    ///
+    /// The visible area is the buffer, or the viewport when drawing inside [`Self::render_viewports`].
+    /// Rotated and scaled instances are culled by their bounding box.
+    /// Useful for profiling.
+    ///
+    /// # Returns
+    ///
+    /// - Amount of instances not send to the GPU in the previous frame.
+    #[inline]
+    #[must_use]
+    pub fn culled_instances(&self) -> usize {
+        self.read(|ctx| ctx.graphics.previous_instance_counts.1)
+    }
+
     /// Get the blending factor between the update states used in the render state.
     ///
     /// This is only set for [`crate::Game::render`].
@@ -343,6 +466,21 @@ impl Context {
         self.read(|ctx| ctx.input.mouse().map(|(_x, y)| y))
     }
 
+    /// Get how much the mouse moved on the device in the last update tick.
+    ///
+    /// Unlike [`Self::mouse`] this is not limited by the edges of the window or the screen, which makes it suitable for mouse-look when the cursor is grabbed with [`Self::set_cursor_grab`].
+    /// The values are raw device units, so they don't correspond to pixels on the buffer and depend on the mouse sensitivity.
+    ///
+    /// # Returns
+    ///
+    /// - `(0.0, 0.0)` when the mouse didn't move.
+    /// - `(x, y)` with the accumulated movement, where a positive `y` is down.
+    #[inline]
+    #[must_use]
+    pub fn mouse_motion(&self) -> (f32, f32) {
+        self.read(|ctx| ctx.input.mouse_motion())
+    }
+
     /// Whether the mouse button goes from "not pressed" to "pressed".
     ///
     /// # Arguments
@@ -388,6 +526,58 @@ impl Context {
         self.read(|ctx| ctx.input.mouse_held(mouse_button))
     }
 
+    /// How many update ticks the mouse button is in a "pressed" state.
+    ///
+    /// Useful for charging attacks.
+    ///
+    /// # Arguments
+    ///
+    /// * `mouse_button` - Mouse button to check the state of.
+    ///
+    /// # Returns
+    ///
+    /// - `0` when the mouse button is not held.
+    /// - `1` in the update tick the mouse button goes from "not pressed" to "pressed", increasing every update tick after.
+    #[inline]
+    #[must_use]
+    pub fn mouse_held_duration(&self, mouse_button: MouseButton) -> u32 {
+        self.read(|ctx| ctx.input.mouse_held_duration(mouse_button))
+    }
+
+    /// Whether the mouse button went from "not pressed" to "pressed" recently.
+    ///
+    /// Useful for buffering input, so a press slightly before it can be handled still counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `mouse_button` - Mouse button to check the state of.
+    /// * `ticks` - Amount of update ticks to look back, including the current one, so `1` is the same as [`Self::mouse_pressed`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the mouse button is pressed in the last `ticks` update ticks.
+    #[inline]
+    #[must_use]
+    pub fn mouse_pressed_within(&self, mouse_button: MouseButton, ticks: u32) -> bool {
+        self.read(|ctx| ctx.input.mouse_pressed_within(mouse_button, ticks))
+    }
+
+    /// Whether the mouse button went from "pressed" to "not pressed" recently.
+    ///
+    /// # Arguments
+    ///
+    /// * `mouse_button` - Mouse button to check the state of.
+    /// * `ticks` - Amount of update ticks to look back, including the current one, so `1` is the same as [`Self::mouse_released`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the mouse button is released in the last `ticks` update ticks.
+    #[inline]
+    #[must_use]
+    pub fn mouse_released_within(&self, mouse_button: MouseButton, ticks: u32) -> bool {
+        self.read(|ctx| ctx.input.mouse_released_within(mouse_button, ticks))
+    }
+
     /// How much the mouse scroll wheel changed in the last update tick.
     ///
     /// # Returns
@@ -400,6 +590,100 @@ impl Context {
     }
 }
 
+/// Touch input methods.
+impl Context {
+    /// List the IDs of all fingers touching the screen.
+    ///
+    /// Fingers lifted from the screen are included for the update tick they are released in.
+    ///
+    /// # Returns
+    ///
+    /// - Stack allocated array of touch IDs in the order the fingers started touching the screen, an ID is only valid for the duration of a single touch.
+    #[inline]
+    #[must_use]
+    pub fn touch_ids(&self) -> SmallVec<[u64; 4]> {
+        self.read(|ctx| ctx.input.touch_ids())
+    }
+
+    /// Get the absolute position of a finger touching the screen.
+    ///
+    /// Mapped the same way as [`Self::mouse`].
+    ///
+    /// # Arguments
+    ///
+    /// * `touch_id` - ID of the finger, must be retrieved with [`Self::touch_ids`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` when the touch is not found or is not on the buffer of pixels.
+    /// - `Some(..)` with the coordinates of the pixel if the touch is on the buffer of pixels.
+    #[inline]
+    #[must_use]
+    pub fn touch_position(&self, touch_id: u64) -> Option<(f32, f32)> {
+        self.read(|ctx| ctx.input.touch_position(touch_id))
+    }
+
+    /// Whether a finger started touching the screen.
+    ///
+    /// # Arguments
+    ///
+    /// * `touch_id` - ID of the finger, must be retrieved with [`Self::touch_ids`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the finger started touching the screen in the last update tick.
+    #[inline]
+    #[must_use]
+    pub fn touch_pressed(&self, touch_id: u64) -> bool {
+        self.read(|ctx| ctx.input.touch_pressed(touch_id))
+    }
+
+    /// Whether a finger is lifted from the screen.
+    ///
+    /// # Arguments
+    ///
+    /// * `touch_id` - ID of the finger, must be retrieved with [`Self::touch_ids`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the finger is lifted in the last update tick.
+    #[inline]
+    #[must_use]
+    pub fn touch_released(&self, touch_id: u64) -> bool {
+        self.read(|ctx| ctx.input.touch_released(touch_id))
+    }
+
+    /// Whether a finger is touching the screen.
+    ///
+    /// # Arguments
+    ///
+    /// * `touch_id` - ID of the finger, must be retrieved with [`Self::touch_ids`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the finger is touching the screen.
+    #[inline]
+    #[must_use]
+    pub fn touch_held(&self, touch_id: u64) -> bool {
+        self.read(|ctx| ctx.input.touch_held(touch_id))
+    }
+
+    /// Let the first finger touching the screen act as the left mouse button.
+    ///
+    /// Makes games only handling the mouse playable on touch screens.
+    /// The mouse position follows the finger, other fingers touching the screen at the same time are ignored.
+    ///
+    /// Disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `emulate` - Whether to emulate the mouse.
+    #[inline]
+    pub fn set_touch_emulates_mouse(&self, emulate: bool) {
+        self.write(|ctx| ctx.input.set_touch_emulates_mouse(emulate));
+    }
+}
+
 /// Keyboard input methods.
 impl Context {
     /// Whether the key goes from "not pressed" to "pressed".
@@ -436,9 +720,10 @@ impl Context {
         self.read(|ctx| ctx.input.key_released(keycode))
     }
 
-    /// Whether the key is in a "pressed" state.
+    /// Whether the key goes from "not pressed" to "pressed", or is repeated by the OS because it's being held.
     ///
-    /// Uses physical keys in the US layout, so for example the W key will be in the same physical key on both US and french keyboards.
+    /// Useful for keys such as backspace and the arrow keys when editing text.
+    /// The repeat delay and rate is determined by the OS.
     ///
     /// # Arguments
     ///
@@ -446,142 +731,707 @@ impl Context {
     ///
     /// # Returns
     ///
-    /// - `true` when the specified key is being held.
+    /// - `true` when the specified key is pressed or repeated.
     #[inline]
     #[must_use]
-    pub fn key_held(&self, keycode: KeyCode) -> bool {
-        self.read(|ctx| ctx.input.key_held(keycode))
+    pub fn key_pressed_or_repeated(&self, keycode: KeyCode) -> bool {
+        self.read(|ctx| ctx.input.key_pressed_or_repeated(keycode))
     }
-}
 
-/// Gamepad input methods.
-impl Context {
-    /// List all connected gamepads.
-    ///
-    /// Required for querying gamepad state and handling input in the other functions.
+    /// Text typed in the last update tick.
     ///
-    /// The array is stack allocated up to 4 connected gamepads, after that it will be heap allocated.
+    /// Unlike the key methods this respects the keyboard layout and modifiers, so it can be used for entering names or chat messages.
+    /// Control characters such as backspace and enter are not included, handle them with [`Self::key_pressed_or_repeated`].
     ///
     /// # Returns
     ///
-    /// - Stack allocated array of all currently connected gamepad IDs.
+    /// - Characters typed this update tick, including text committed by an input method editor, see [`Self::set_ime_allowed`].
+    ///   Only allocates when something is typed, so it can be polled every update tick.
+    /// - An empty string when nothing is typed.
     #[inline]
     #[must_use]
-    pub fn gamepad_ids(&self) -> SmallVec<[GamepadId; 4]> {
-        self.read(|ctx| ctx.input.gamepads_ids())
+    pub fn text_input(&self) -> String {
+        self.read(|ctx| ctx.input.text_input().to_owned())
     }
 
-    /// Whether a gamepad button goes from "not pressed" to "pressed".
+    /// Whether the key is in a "pressed" state.
+    ///
+    /// Uses physical keys in the US layout, so for example the W key will be in the same physical key on both US and french keyboards.
     ///
     /// # Arguments
     ///
-    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
-    /// * `button` - Which button on the gamepad to check.
+    /// * `keycode` - Key to check the state of.
     ///
     /// # Returns
     ///
-    /// - `None` when gamepad is not found or is disconnected.
-    /// - `Some(true)` when gamepad button is being pressed this update tick.
+    /// - `true` when the specified key is being held.
     #[inline]
     #[must_use]
-    pub fn gamepad_button_pressed(
-        &self,
-        gamepad_id: GamepadId,
-        button: GamepadButton,
-    ) -> Option<bool> {
-        self.read(|ctx| ctx.input.gamepad_button_pressed(gamepad_id, button))
+    pub fn key_held(&self, keycode: KeyCode) -> bool {
+        self.read(|ctx| ctx.input.key_held(keycode))
     }
 
-    /// Whether a gamepad button goes from "pressed" to "not pressed".
+    /// How many update ticks the key is in a "pressed" state.
+    ///
+    /// Useful for charging attacks.
     ///
     /// # Arguments
     ///
-    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
-    /// * `button` - Which button on the gamepad to check.
+    /// * `keycode` - Key to check the state of.
     ///
     /// # Returns
     ///
-    /// - `None` when gamepad is not found or is disconnected.
-    /// - `Some(true)` when gamepad button is being released this update tick.
+    /// - `0` when the key is not held.
+    /// - `1` in the update tick the key goes from "not pressed" to "pressed", increasing every update tick after.
     #[inline]
     #[must_use]
-    pub fn gamepad_button_released(
-        &self,
-        gamepad_id: GamepadId,
-        button: GamepadButton,
-    ) -> Option<bool> {
-        self.read(|ctx| ctx.input.gamepad_button_released(gamepad_id, button))
+    pub fn key_held_duration(&self, keycode: KeyCode) -> u32 {
+        self.read(|ctx| ctx.input.key_held_duration(keycode))
     }
 
-    /// Whether a gamepad button is in a "pressed" state.
+    /// Whether the key went from "not pressed" to "pressed" recently.
+    ///
+    /// Useful for buffering input, for example to still jump when the key is pressed slightly before landing.
     ///
     /// # Arguments
     ///
-    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
-    /// * `button` - Which button on the gamepad to check.
+    /// * `keycode` - Key to check the state of.
+    /// * `ticks` - Amount of update ticks to look back, including the current one, so `1` is the same as [`Self::key_pressed`].
     ///
     /// # Returns
     ///
-    /// - `None` when gamepad is not found or is disconnected.
-    /// - `Some(true)` when gamepad button is being pressed.
-    /// - `Some(false)` when gamepad button is released.
+    /// - `true` when the key is pressed in the last `ticks` update ticks.
     #[inline]
     #[must_use]
-    pub fn gamepad_button_held(
-        &self,
-        gamepad_id: GamepadId,
-        button: GamepadButton,
-    ) -> Option<bool> {
-        self.read(|ctx| ctx.input.gamepad_button_held(gamepad_id, button))
+    pub fn key_pressed_within(&self, keycode: KeyCode, ticks: u32) -> bool {
+        self.read(|ctx| ctx.input.key_pressed_within(keycode, ticks))
     }
 
-    /// "Value" of a gamepad button between 0.0 and 1.0.
-    ///
-    /// Used for triggers.
+    /// Whether the key went from "pressed" to "not pressed" recently.
     ///
     /// # Arguments
     ///
-    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
-    /// * `button` - Which button element on the gamepad to check.
+    /// * `keycode` - Key to check the state of.
+    /// * `ticks` - Amount of update ticks to look back, including the current one, so `1` is the same as [`Self::key_released`].
     ///
     /// # Returns
     ///
-    /// - `None` when gamepad is not found or is disconnected.
-    /// - `Some(0.0)` when gamepad axis element is not moved.
-    /// - `Some(1.0)` when gamepad axis element is fully engaged.
+    /// - `true` when the key is released in the last `ticks` update ticks.
     #[inline]
     #[must_use]
-    pub fn gamepad_button_value(
-        &self,
-        gamepad_id: GamepadId,
-        button: GamepadButton,
-    ) -> Option<f32> {
-        self.read(|ctx| ctx.input.gamepad_button_value(gamepad_id, button))
+    pub fn key_released_within(&self, keycode: KeyCode, ticks: u32) -> bool {
+        self.read(|ctx| ctx.input.key_released_within(keycode, ticks))
     }
+}
 
-    /// "Value" of a gamepad element between -1.0 and 1.0.
-    ///
-    /// Used for sticks.
+/// Gamepad input methods.
+impl Context {
+    /// List all connected gamepads.
     ///
-    /// # Arguments
+    /// Required for querying gamepad state and handling input in the other functions.
     ///
-    /// * `gamepad_id` - ID of the gamepad to check the axis of, must be retrieved with [`Self::gamepad_ids`].
-    /// * `axis` - Which axis element on the gamepad to check.
+    /// The array is stack allocated up to 4 connected gamepads, after that it will be heap allocated.
     ///
     /// # Returns
     ///
-    /// - `None` when gamepad is not found or is disconnected.
-    /// - `Some(0.0)` when gamepad axis element is not moved.
-    /// - `Some(1.0)` when gamepad axis element is fully engaged.
-    /// - `Some(-1.0)` when gamepad axis element is fully negatively engaged, for example a horizontal axis being moved left.
+    /// - Stack allocated array of all currently connected gamepad IDs.
     #[inline]
     #[must_use]
-    pub fn gamepad_axis(&self, gamepad_id: GamepadId, axis: GamepadAxis) -> Option<f32> {
-        self.read(|ctx| ctx.input.gamepad_axis(gamepad_id, axis))
+    pub fn gamepad_ids(&self) -> SmallVec<[GamepadId; 4]> {
+        self.read(|ctx| ctx.input.gamepads_ids())
     }
-}
 
-/// Generic asset loading.
+    /// List gamepads connected in the last update tick.
+    ///
+    /// Also includes gamepads that are connected when the game starts.
+    ///
+    /// # Returns
+    ///
+    /// - Stack allocated array of all gamepad IDs that got connected.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_connected_ids(&self) -> SmallVec<[GamepadId; 4]> {
+        self.read(|ctx| ctx.input.gamepads_connected())
+    }
+
+    /// List gamepads disconnected in the last update tick.
+    ///
+    /// # Returns
+    ///
+    /// - Stack allocated array of all gamepad IDs that got disconnected, they can't be used anymore for querying state.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_disconnected_ids(&self) -> SmallVec<[GamepadId; 4]> {
+        self.read(|ctx| ctx.input.gamepads_disconnected())
+    }
+
+    /// Name of a gamepad as reported by the OS or the mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad, must be retrieved with [`Self::gamepad_ids`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found or is disconnected.
+    /// - `Some(..)` with the name of the gamepad.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_name(&self, gamepad_id: GamepadId) -> Option<String> {
+        self.read(|ctx| ctx.input.gamepad_name(gamepad_id))
+    }
+
+    /// Name of the SDL mapping used to map the buttons and axes of a gamepad.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad, must be retrieved with [`Self::gamepad_ids`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found, is disconnected or has no mapping.
+    /// - `Some(..)` with the name of the mapping.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_mapping_name(&self, gamepad_id: GamepadId) -> Option<String> {
+        self.read(|ctx| ctx.input.gamepad_mapping_name(gamepad_id))
+    }
+
+    /// UUID of a gamepad, which is the same for the same model every time it's connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad, must be retrieved with [`Self::gamepad_ids`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found or is disconnected.
+    /// - `Some(..)` with the bytes of the UUID.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_uuid(&self, gamepad_id: GamepadId) -> Option<[u8; 16]> {
+        self.read(|ctx| ctx.input.gamepad_uuid(gamepad_id))
+    }
+
+    /// Rumble a gamepad with force feedback.
+    ///
+    /// Replaces the previous rumble of the gamepad, call with a strength of `0.0` to stop rumbling.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad, must be retrieved with [`Self::gamepad_ids`].
+    /// * `strength` - How strong both motors rumble between `0.0` and `1.0`.
+    /// * `duration` - How long to rumble in seconds.
+    ///
+    /// # Returns
+    ///
+    /// - `false` when gamepad is not found, is disconnected, doesn't support force feedback or the rumble couldn't be played.
+    /// - `true` when the gamepad is rumbling.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_rumble(&self, gamepad_id: GamepadId, strength: f32, duration: f32) -> bool {
+        self.write(|ctx| ctx.input.gamepad_rumble(gamepad_id, strength, duration))
+    }
+
+    /// Whether a gamepad button goes from "not pressed" to "pressed".
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
+    /// * `button` - Which button on the gamepad to check.
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found or is disconnected.
+    /// - `Some(true)` when gamepad button is being pressed this update tick.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_pressed(
+        &self,
+        gamepad_id: GamepadId,
+        button: GamepadButton,
+    ) -> Option<bool> {
+        self.read(|ctx| ctx.input.gamepad_button_pressed(gamepad_id, button))
+    }
+
+    /// Whether a gamepad button goes from "pressed" to "not pressed".
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
+    /// * `button` - Which button on the gamepad to check.
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found or is disconnected.
+    /// - `Some(true)` when gamepad button is being released this update tick.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_released(
+        &self,
+        gamepad_id: GamepadId,
+        button: GamepadButton,
+    ) -> Option<bool> {
+        self.read(|ctx| ctx.input.gamepad_button_released(gamepad_id, button))
+    }
+
+    /// Whether a gamepad button is in a "pressed" state.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
+    /// * `button` - Which button on the gamepad to check.
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found or is disconnected.
+    /// - `Some(true)` when gamepad button is being pressed.
+    /// - `Some(false)` when gamepad button is released.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_held(
+        &self,
+        gamepad_id: GamepadId,
+        button: GamepadButton,
+    ) -> Option<bool> {
+        self.read(|ctx| ctx.input.gamepad_button_held(gamepad_id, button))
+    }
+
+    /// How many update ticks a gamepad button is in a "pressed" state.
+    ///
+    /// Useful for charging attacks.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
+    /// * `button` - Which button on the gamepad to check.
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found or is disconnected.
+    /// - `Some(0)` when the gamepad button is not held.
+    /// - `Some(1)` in the update tick the gamepad button goes from "not pressed" to "pressed", increasing every update tick after.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_held_duration(
+        &self,
+        gamepad_id: GamepadId,
+        button: GamepadButton,
+    ) -> Option<u32> {
+        self.read(|ctx| ctx.input.gamepad_button_held_duration(gamepad_id, button))
+    }
+
+    /// Whether a gamepad button went from "not pressed" to "pressed" recently.
+    ///
+    /// Useful for buffering input, for example to still jump when the button is pressed slightly before landing.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
+    /// * `button` - Which button on the gamepad to check.
+    /// * `ticks` - Amount of update ticks to look back, including the current one, so `1` is the same as [`Self::gamepad_button_pressed`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found or is disconnected.
+    /// - `Some(true)` when the gamepad button is pressed in the last `ticks` update ticks.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_pressed_within(
+        &self,
+        gamepad_id: GamepadId,
+        button: GamepadButton,
+        ticks: u32,
+    ) -> Option<bool> {
+        self.read(|ctx| {
+            ctx.input
+                .gamepad_button_pressed_within(gamepad_id, button, ticks)
+        })
+    }
+
+    /// Whether a gamepad button went from "pressed" to "not pressed" recently.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
+    /// * `button` - Which button on the gamepad to check.
+    /// * `ticks` - Amount of update ticks to look back, including the current one, so `1` is the same as [`Self::gamepad_button_released`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found or is disconnected.
+    /// - `Some(true)` when the gamepad button is released in the last `ticks` update ticks.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_released_within(
+        &self,
+        gamepad_id: GamepadId,
+        button: GamepadButton,
+        ticks: u32,
+    ) -> Option<bool> {
+        self.read(|ctx| {
+            ctx.input
+                .gamepad_button_released_within(gamepad_id, button, ticks)
+        })
+    }
+
+    /// "Value" of a gamepad button between 0.0 and 1.0.
+    ///
+    /// Used for triggers.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad to check the button of, must be retrieved with [`Self::gamepad_ids`].
+    /// * `button` - Which button element on the gamepad to check.
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found or is disconnected.
+    /// - `Some(0.0)` when gamepad axis element is not moved.
+    /// - `Some(1.0)` when gamepad axis element is fully engaged.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_value(
+        &self,
+        gamepad_id: GamepadId,
+        button: GamepadButton,
+    ) -> Option<f32> {
+        self.read(|ctx| ctx.input.gamepad_button_value(gamepad_id, button))
+    }
+
+    /// "Value" of a gamepad element between -1.0 and 1.0.
+    ///
+    /// Used for sticks.
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad to check the axis of, must be retrieved with [`Self::gamepad_ids`].
+    /// * `axis` - Which axis element on the gamepad to check.
+    ///
+    /// # Returns
+    ///
+    /// - `None` when gamepad is not found or is disconnected.
+    /// - `Some(0.0)` when gamepad axis element is not moved.
+    /// - `Some(1.0)` when gamepad axis element is fully engaged.
+    /// - `Some(-1.0)` when gamepad axis element is fully negatively engaged, for example a horizontal axis being moved left.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_axis(&self, gamepad_id: GamepadId, axis: GamepadAxis) -> Option<f32> {
+        self.read(|ctx| ctx.input.gamepad_axis(gamepad_id, axis))
+    }
+
+    /// Position of a gamepad stick with the deadzones and response curve applied.
+    ///
+    /// Unlike [`Self::gamepad_axis`] the raw values are filtered, see [`Self::set_gamepad_stick_settings`].
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad to check the stick of, must be retrieved with [`Self::gamepad_ids`].
+    /// * `stick` - Which stick on the gamepad to check.
+    ///
+    /// # Returns
+    ///
+    /// - `(0.0, 0.0)` when the stick is inside the inner deadzone, or when the gamepad is not found or is disconnected.
+    /// - `(x, y)` between `-1.0` and `1.0`, where a positive `y` is up.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_stick(&self, gamepad_id: GamepadId, stick: Stick) -> (f32, f32) {
+        self.read(|ctx| ctx.input.gamepad_stick(gamepad_id, stick))
+    }
+
+    /// Set the deadzones and response curve of the sticks for all gamepads.
+    ///
+    /// Applied by [`Self::gamepad_stick`].
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Deadzones and response curve.
+    #[inline]
+    pub fn set_gamepad_stick_settings(&self, settings: StickSettings) {
+        self.write(|ctx| ctx.input.set_stick_settings(settings));
+    }
+
+    /// Overwrite the deadzones and response curve of the sticks for a single gamepad.
+    ///
+    /// Applied by [`Self::gamepad_stick`].
+    ///
+    /// # Arguments
+    ///
+    /// * `gamepad_id` - ID of the gamepad, must be retrieved with [`Self::gamepad_ids`].
+    /// * `settings` - Deadzones and response curve, `None` uses the settings from [`Self::set_gamepad_stick_settings`] again.
+    #[inline]
+    pub fn set_gamepad_stick_settings_for(
+        &self,
+        gamepad_id: GamepadId,
+        settings: Option<StickSettings>,
+    ) {
+        self.write(|ctx| ctx.input.set_gamepad_stick_settings(gamepad_id, settings));
+    }
+}
+
+/// Input action methods.
+impl Context {
+    /// Whether any binding of an action goes from "not pressed" to "pressed".
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - Name of the action, bound with [`Self::bind_action`] or [`Self::load_input_bindings`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the action is pressed this update tick.
+    /// - `false` when the action doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn action_pressed(&self, action: &str) -> bool {
+        self.read(|ctx| ctx.input.action_pressed(action))
+    }
+
+    /// Whether all bindings of an action go from "pressed" to "not pressed".
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - Name of the action, bound with [`Self::bind_action`] or [`Self::load_input_bindings`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the action is released this update tick.
+    /// - `false` when the action doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn action_released(&self, action: &str) -> bool {
+        self.read(|ctx| ctx.input.action_released(action))
+    }
+
+    /// Whether any binding of an action is in a "pressed" state.
+    ///
+    /// Analog bindings such as gamepad axes count as pressed when engaged more than halfway.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - Name of the action, bound with [`Self::bind_action`] or [`Self::load_input_bindings`].
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the action is being held.
+    /// - `false` when the action doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn action_held(&self, action: &str) -> bool {
+        self.read(|ctx| ctx.input.action_held(action))
+    }
+
+    /// Value of a named axis.
+    ///
+    /// When multiple bindings are engaged in the same direction the strongest is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Name of the axis, bound with [`Self::bind_axis`] or [`Self::load_input_bindings`].
+    ///
+    /// # Returns
+    ///
+    /// - Value between `-1.0` and `1.0`, for keys and buttons this is always `-1.0`, `0.0` or `1.0`.
+    /// - `0.0` when the axis doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn action_axis(&self, axis: &str) -> f32 {
+        self.read(|ctx| ctx.input.action_axis(axis))
+    }
+
+    /// Add a binding to an action, creating the action if it doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - Name of the action.
+    /// * `binding` - Physical input triggering the action.
+    #[inline]
+    pub fn bind_action(&self, action: impl Into<String>, binding: Binding) {
+        // Reduce compilation times
+        fn inner(this: &Context, action: String, binding: Binding) {
+            this.write(|ctx| ctx.input.bind_action(action, binding));
+        }
+
+        inner(self, action.into(), binding);
+    }
+
+    /// Remove all bindings from an action.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - Name of the action.
+    #[inline]
+    pub fn unbind_action(&self, action: &str) {
+        self.write(|ctx| ctx.input.unbind_action(action));
+    }
+
+    /// Add a pair of bindings to an axis, creating the axis if it doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Name of the axis.
+    /// * `negative` - Physical input moving the axis towards `-1.0`.
+    /// * `positive` - Physical input moving the axis towards `1.0`.
+    #[inline]
+    pub fn bind_axis(&self, axis: impl Into<String>, negative: Binding, positive: Binding) {
+        // Reduce compilation times
+        fn inner(this: &Context, axis: String, negative: Binding, positive: Binding) {
+            this.write(|ctx| ctx.input.bind_axis(axis, negative, positive));
+        }
+
+        inner(self, axis.into(), negative, positive);
+    }
+
+    /// Remove all bindings from an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - Name of the axis.
+    #[inline]
+    pub fn unbind_axis(&self, axis: &str) {
+        self.write(|ctx| ctx.input.unbind_axis(axis));
+    }
+
+    /// Replace all action and axis bindings.
+    ///
+    /// Stops reloading the bindings from an asset loaded with [`Self::load_input_bindings`].
+    ///
+    /// # Arguments
+    ///
+    /// * `bindings` - New bindings for all actions and axes.
+    #[inline]
+    pub fn set_input_bindings(&self, bindings: InputBindings) {
+        self.write(|ctx| {
+            ctx.input.bindings_asset = None;
+            ctx.input.set_bindings(bindings);
+        });
+    }
+
+    /// Replace all action and axis bindings with a RON asset.
+    ///
+    /// When developing on the desktop the bindings are hot-reloaded when the asset changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Asset path of the RON file, see [`InputBindings`] for the format.
+    ///
+    /// # Panics
+    ///
+    /// - When asset with path does not exist.
+    /// - When asset could not be loaded due to an invalid format.
+    #[inline]
+    pub fn load_input_bindings(&self, path: impl AsRef<str>) {
+        // Reduce compilation times
+        fn inner(this: &Context, path: &str) {
+            this.write(|ctx| ctx.load_input_bindings(&Id::new(path)));
+        }
+
+        inner(self, path.as_ref());
+    }
+
+    /// Get a copy of all action and axis bindings.
+    ///
+    /// Can be saved with [`InputBindings::to_ron`] after rebinding.
+    ///
+    /// # Returns
+    ///
+    /// - All bindings, including the ones changed at runtime.
+    #[inline]
+    #[must_use]
+    pub fn input_bindings(&self) -> InputBindings {
+        self.read(|ctx| ctx.input.bindings().clone())
+    }
+
+    /// Capture the next pressed key, mouse button, gamepad button or moved gamepad axis.
+    ///
+    /// Until an input is captured all actions and axes are disabled, which is useful for rebinding in a settings screen.
+    /// Get the captured input with [`Self::captured_input`].
+    #[inline]
+    pub fn capture_next_input(&self) {
+        self.write(|ctx| ctx.input.capture_next_input());
+    }
+
+    /// Take the input captured after calling [`Self::capture_next_input`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` when nothing has been captured yet.
+    /// - `Some(..)` with the binding of the captured input once, after that `None` is returned again.
+    #[inline]
+    #[must_use]
+    pub fn captured_input(&self) -> Option<Binding> {
+        self.write(|ctx| ctx.input.take_captured())
+    }
+}
+
+/// Input recording methods.
+impl Context {
+    /// Start recording all keyboard, mouse, touch and gamepad input per update tick.
+    ///
+    /// Keys and buttons already held, and gamepads already connected, are recorded as being there from the start.
+    /// When already recording the previous recording is discarded.
+    #[inline]
+    pub fn start_recording(&self) {
+        self.write(|ctx| ctx.input.start_recording());
+    }
+
+    /// Stop recording input.
+    ///
+    /// # Returns
+    ///
+    /// - The recording, which can be replayed with [`Self::replay`] or saved with [`InputRecording::to_bytes`].
+    /// - An empty recording when [`Self::start_recording`] wasn't called.
+    #[inline]
+    #[must_use]
+    pub fn stop_recording(&self) -> InputRecording {
+        self.write(|ctx| ctx.input.stop_recording())
+    }
+
+    /// Whether input is being recorded.
+    ///
+    /// # Returns
+    ///
+    /// - `true` between [`Self::start_recording`] and [`Self::stop_recording`].
+    #[inline]
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.read(|ctx| ctx.input.is_recording())
+    }
+
+    /// Replay recorded input instead of the real input.
+    ///
+    /// All keys and buttons are released first, after that the state is taken from the recording every update tick until it's finished.
+    /// The real gamepads are replaced by the recorded ones, which get an ID in the order they were connected during the recording starting at `0`.
+    ///
+    /// The game only behaves exactly the same as when recording when all other state is the same as well, for example when the random number generator is seeded the same.
+    ///
+    /// # Arguments
+    ///
+    /// * `recording` - Recording created with [`Self::stop_recording`].
+    #[inline]
+    pub fn replay(&self, recording: InputRecording) {
+        self.write(|ctx| ctx.input.start_replay(recording));
+    }
+
+    /// Stop replaying recorded input before it's finished, returning to the real input.
+    #[inline]
+    pub fn stop_replay(&self) {
+        self.write(|ctx| ctx.input.stop_replay());
+    }
+
+    /// Whether recorded input is being replayed.
+    ///
+    /// # Returns
+    ///
+    /// - `true` after calling [`Self::replay`] until all update ticks of the recording have been replayed.
+    #[inline]
+    #[must_use]
+    pub fn is_replaying(&self) -> bool {
+        self.read(|ctx| ctx.input.is_replaying())
+    }
+}
+
+/// Generic asset loading.
 impl Context {
     /// Load a read-only reference to a custom defined asset.
     ///
@@ -664,6 +1514,23 @@ impl Context {
         reader(&self.inner.borrow())
     }
 
+    /// Draw the cursor sprite at the mouse position.
+    pub(crate) fn draw_cursor_sprite(&self) {
+        let Some((path, mouse)) = self.read(|ctx| {
+            // Map the mouse back through the UI camera, which transforms the sprite again when drawing
+            let mouse = ctx.input.mouse().map(|(mouse_x, mouse_y)| {
+                ctx.camera_by_id(CameraId::Ui)
+                    .inverse_transform_point(mouse_x, mouse_y)
+            });
+
+            ctx.cursor_sprite.clone().zip(mouse)
+        }) else {
+            return;
+        };
+
+        self.sprite(&path).use_ui_camera().translate(mouse).draw();
+    }
+
     /// Get a mutable reference to the inner struct.
     ///
     /// # Panics
@@ -691,14 +1558,38 @@ pub struct ContextInner {
     pub(crate) main_camera: Camera,
     /// UI camera state.
     pub(crate) ui_camera: Camera,
+    /// User created cameras by name, in the order they were created.
+    pub(crate) cameras: Vec<(String, Camera)>,
+    /// Index of the named camera replacing the main camera while rendering its viewport.
+    pub(crate) active_camera: Option<usize>,
+    /// Immediate-mode user interface state.
+    pub(crate) ui: Ui,
     /// Frames per second for the render tick.
     pub(crate) frames_per_second: f32,
     /// Interpolation alpha for the render tick.
     pub(crate) blending_factor: f32,
     /// Input manager.
     pub(crate) input: Input,
-    /// Audio manager for playing audio.
-    pub(crate) audio_manager: AudioManager<DefaultBackend>,
+    /// Audio manager for playing audio, silent when there's no audio device.
+    pub(crate) audio_manager: AudioBackend,
+    /// Sounds that are playing.
+    pub(crate) sounds: HashMap<SoundHandle, PlayingSound>,
+    /// Identifier of the next sound that's played.
+    pub(crate) next_sound: u32,
+    /// Sounds played at a world position.
+    pub(crate) emitters: HashMap<SoundHandle, Emitter>,
+    /// Falloff and maximum distance of sounds played at a world position.
+    pub(crate) spatial_settings: SpatialSettings,
+    /// Background music crossfading between songs.
+    pub(crate) music_player: MusicPlayer,
+    /// Named musical clocks sounds can be synchronized to.
+    pub(crate) clocks: HashMap<String, BeatClock>,
+    /// Named mixer tracks sounds can be played on.
+    pub(crate) tracks: HashMap<String, MixerTrack>,
+    /// Volume of the main mixer track when not muted.
+    pub(crate) master_volume: Decibels,
+    /// Whether the main mixer track is silenced.
+    pub(crate) master_muted: bool,
     /// User supplied game configuration.
     pub(crate) config: Config,
     /// Sprite assets.
@@ -711,6 +1602,8 @@ pub struct ContextInner {
     pub(crate) shaders: AssetManager<Shader>,
     /// Custom type erased assets.
     pub(crate) custom: CustomAssetManager,
+    /// Sprite drawn at the mouse position.
+    pub(crate) cursor_sprite: Option<String>,
     /// Whether to exit.
     pub(crate) exit: bool,
 }
@@ -735,8 +1628,24 @@ impl ContextInner {
         // The UI camera is top-left
         let ui_camera = Camera::default();
 
-        // Setup the audio manager to play audio
-        let audio_manager = AudioManager::new(AudioManagerSettings::default()).unwrap();
+        // There's no user created cameras yet
+        let cameras = Vec::new();
+        let active_camera = None;
+
+        // Setup the user interface without a style
+        let ui = Ui::new();
+
+        // Setup the audio manager to play audio, without panicking when there's no audio device
+        let audio_manager = AudioBackend::new();
+        let sounds = HashMap::new();
+        let next_sound = 0;
+        let emitters = HashMap::new();
+        let spatial_settings = SpatialSettings::default();
+        let music_player = MusicPlayer::default();
+        let clocks = HashMap::new();
+        let tracks = HashMap::new();
+        let master_volume = Decibels::IDENTITY;
+        let master_muted = false;
 
         // Setup the assets managers
         let sprites = AssetManager::default();
@@ -751,6 +1660,7 @@ impl ContextInner {
 
         // Default input values and state
         let input = Input::new();
+        let cursor_sprite = None;
         let exit = false;
 
         Self {
@@ -759,16 +1669,29 @@ impl ContextInner {
             graphics,
             main_camera,
             ui_camera,
+            cameras,
+            active_camera,
+            ui,
             frames_per_second,
             blending_factor,
             input,
             audio_manager,
+            sounds,
+            next_sound,
+            emitters,
+            spatial_settings,
+            music_player,
+            clocks,
+            tracks,
+            master_volume,
+            master_muted,
             config,
             sprites,
             fonts,
             audio,
             shaders,
             custom,
+            cursor_sprite,
             exit,
         }
     }
@@ -813,6 +1736,134 @@ impl ContextInner {
         self.fonts.insert(id, asset)
     }
 
+    /// Get a named mixer track, creating it if it doesn't exist yet.
+    ///
+    /// # Panics
+    ///
+    /// - When the maximum amount of tracks is reached.
+    pub(crate) fn track_mut(&mut self, name: &str) -> &mut MixerTrack {
+        if !self.tracks.contains_key(name) {
+            let track = MixerTrack::new(&mut self.audio_manager);
+            self.tracks.insert(name.to_owned(), track);
+        }
+
+        self.tracks
+            .get_mut(name)
+            .expect("Mixer track was just created")
+    }
+
+    /// Play a sound on a named mixer track, or on the main track.
+    pub(crate) fn play_sound<D>(
+        &mut self,
+        track: Option<&str>,
+        sound_data: D,
+    ) -> Result<D::Handle, PlaySoundError<D::Error>>
+    where
+        D: SoundData,
+    {
+        match track {
+            Some(name) => self.track_mut(name).handle.play(sound_data),
+            None => self.audio_manager.play(sound_data),
+        }
+    }
+
+    /// Store the handle of a sound that started playing.
+    pub(crate) fn insert_sound(&mut self, sound: PlayingSound) -> SoundHandle {
+        // Forget the sounds that are finished
+        self.sounds
+            .retain(|_, sound| sound.state() != PlaybackState::Stopped);
+        self.emitters
+            .retain(|handle, _| self.sounds.contains_key(handle));
+
+        let handle = SoundHandle(self.next_sound);
+        self.next_sound = self.next_sound.wrapping_add(1);
+        self.sounds.insert(handle, sound);
+
+        handle
+    }
+
+    /// Volume factor and panning of a sound at a world position, relative to the main camera.
+    pub(crate) fn spatialize(&self, x: f32, y: f32) -> (f32, f32) {
+        let (buffer_width, buffer_height) = (self.config.buffer_width, self.config.buffer_height);
+        let (offset_x, offset_y) =
+            self.main_camera
+                .offset_from_center(x, y, buffer_width, buffer_height);
+
+        self.spatial_settings
+            .apply(offset_x, offset_y, buffer_width)
+    }
+
+    /// Update the volume and panning of all sounds played at a world position.
+    ///
+    /// Must be done in the update tick.
+    pub(crate) fn update_emitters(&mut self) {
+        // Take the emitters so the context can be borrowed while updating them
+        let mut emitters = std::mem::take(&mut self.emitters);
+
+        for (handle, emitter) in &mut emitters {
+            // Calculate the new volume and panning
+            let (gain, panning) = self.spatialize(emitter.x, emitter.y);
+
+            let Some(sound) = self.sounds.get_mut(handle) else {
+                continue;
+            };
+
+            // Only update when changed, so tweens started by the user aren't interrupted
+            if (gain - emitter.gain).abs() > f32::EPSILON {
+                emitter.gain = gain;
+                sound.set_volume(volume_to_decibels(emitter.volume * gain), Tween::default());
+            }
+            if (panning - emitter.panning).abs() > f32::EPSILON {
+                emitter.panning = panning;
+                sound.set_panning(panning_from_balance(panning), Tween::default());
+            }
+        }
+
+        self.emitters = emitters;
+    }
+
+    /// Open an audio file for streaming, without decoding it fully.
+    ///
+    /// # Panics
+    ///
+    /// - When audio asset could not be found or opened.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn music(
+        &self,
+        id: &str,
+    ) -> kira::sound::streaming::StreamingSoundData<kira::sound::FromFileError> {
+        use std::io::Cursor;
+
+        use kira::sound::streaming::StreamingSoundData;
+
+        use crate::assets::loadable::audio::EXTENSIONS;
+
+        // Create the ID
+        let id = Id::new(id);
+
+        // Try every supported format
+        let sound_data = EXTENSIONS
+            .iter()
+            .find_map(|extension| {
+                // Stream embedded bytes from memory without copying them, otherwise stream from disk
+                self.asset_source
+                    .embedded_bytes(&id, extension)
+                    .map(|bytes| StreamingSoundData::from_cursor(Cursor::new(bytes)))
+                    .or_else(|| {
+                        self.asset_source
+                            .runtime_path(&id, extension)
+                            .filter(|path| path.is_file())
+                            .map(StreamingSoundData::from_file)
+                    })
+            })
+            .unwrap_or_else(|| panic!("Error loading music from ID '{id}': asset not found"));
+
+        match sound_data {
+            Ok(sound_data) => sound_data,
+            Err(err) => panic!("Error loading music from ID '{id}': {err}"),
+        }
+    }
+
     /// Get or load an audio file.
     ///
     /// # Panics
@@ -833,6 +1884,27 @@ impl ContextInner {
         self.audio.insert(id, asset)
     }
 
+    /// Get or load the sound data of an audio file, picking a random clip from sound groups.
+    ///
+    /// # Panics
+    ///
+    /// - When audio asset could not be loaded.
+    /// - When a sound group contains another sound group.
+    pub(crate) fn sound_data(&mut self, id: &str) -> StaticSoundData {
+        let clip = match &*self.audio(id) {
+            Audio::Sound(sound_data) => return StaticSoundData::clone(sound_data),
+            Audio::Group(group) => group.pick().to_owned(),
+        };
+
+        // Only a single level is allowed, otherwise groups containing each other would never resolve
+        match &*self.audio(&clip) {
+            Audio::Sound(sound_data) => StaticSoundData::clone(sound_data),
+            Audio::Group(_) => {
+                panic!("Sound group '{id}' contains group '{clip}', which is not allowed")
+            }
+        }
+    }
+
     /// Get or load a custom asset.
     ///
     /// # Panics
@@ -892,6 +1964,21 @@ impl ContextInner {
         self.shaders.insert(id, asset);
     }
 
+    /// Load the input bindings from an asset and remember it for hot-reloading.
+    ///
+    /// # Panics
+    ///
+    /// - When bindings asset could not be loaded.
+    #[inline]
+    pub(crate) fn load_input_bindings(&mut self, id: &Id) {
+        // Load the asset directly without storing it
+        let bindings = InputBindings::load(id, self);
+        self.input.set_bindings(bindings);
+
+        // Remember the asset so it can be reloaded
+        self.input.bindings_asset = Some(id.clone());
+    }
+
     /// Remove all assets with the specified ID if they exist.
     #[cfg(not(target_arch = "wasm32"))]
     #[inline]
@@ -900,23 +1987,58 @@ impl ContextInner {
         self.fonts.remove(id);
         self.audio.remove(id);
         self.custom.remove(id);
+
+        // Reload the input bindings directly, because they are not loaded on demand
+        if self.input.bindings_asset.as_ref() == Some(id) {
+            self.load_input_bindings(id);
+        }
     }
 
-    /// Get a mutable reference to the camera based on whether it's the main camera or the UI camera.
+    /// Get a mutable reference to a camera.
+    ///
+    /// # Panics
+    ///
+    /// - When a named camera doesn't exist.
     #[inline]
-    pub(crate) fn camera_mut(&mut self, is_ui_camera: bool) -> &mut Camera {
-        if is_ui_camera {
-            &mut self.ui_camera
-        } else {
-            &mut self.main_camera
+    pub(crate) fn camera_by_id_mut(&mut self, id: CameraId) -> &mut Camera {
+        match id {
+            CameraId::Main => &mut self.main_camera,
+            CameraId::Ui => &mut self.ui_camera,
+            CameraId::Named(name) => self
+                .cameras
+                .iter_mut()
+                .find_map(|(camera_name, camera)| (camera_name == name).then_some(camera))
+                .expect("Camera does not exist"),
+        }
+    }
+
+    /// Get a reference to a camera.
+    ///
+    /// # Panics
+    ///
+    /// - When a named camera doesn't exist.
+    #[inline]
+    pub(crate) fn camera_by_id(&self, id: CameraId) -> &Camera {
+        match id {
+            CameraId::Main => &self.main_camera,
+            CameraId::Ui => &self.ui_camera,
+            CameraId::Named(name) => self
+                .cameras
+                .iter()
+                .find_map(|(camera_name, camera)| (camera_name == name).then_some(camera))
+                .expect("Camera does not exist"),
         }
     }
 
-    /// Get a reference to the camera based on whether it's the main camera or the UI camera.
+    /// Get a reference to the camera to draw with based on whether it's the main camera or the UI camera.
+    ///
+    /// When rendering the viewports the main camera is replaced by the camera of the viewport being rendered.
     #[inline]
-    pub(crate) const fn camera(&self, is_ui_camera: bool) -> &Camera {
+    pub(crate) fn camera(&self, is_ui_camera: bool) -> &Camera {
         if is_ui_camera {
             &self.ui_camera
+        } else if let Some(index) = self.active_camera {
+            &self.cameras[index].1
         } else {
             &self.main_camera
         }
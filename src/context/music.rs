@@ -0,0 +1,291 @@
+//! Zero-cost abstraction types for controlling the background music.
+
+use kira::sound::PlaybackState;
+
+use crate::{Context, context::audio::SoundHandle, music::MUSIC_TRACK};
+
+/// Control the background music, crossfading between songs.
+///
+/// At most one song is playing at a time, requesting another song fades out the current one while the new one fades in.
+/// All songs are streamed and played on the `"music"` mixer track, so their volume and effects can be changed with [`Context::track`].
+///
+/// Used by [`Context::music_player`].
+pub struct MusicPlayerContext<'ctx> {
+    /// Reference to the context the music is playing in.
+    pub(crate) ctx: &'ctx Context,
+}
+
+impl MusicPlayerContext<'_> {
+    /// Play a song from the start and keep looping it.
+    ///
+    /// Does nothing when the song is already playing.
+    /// Stops the playlist, see [`Self::resume_playlist`] to continue it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Asset path of the audio file, see [`Context`] for more information about asset loading and storing.
+    /// * `crossfade` - Seconds it takes for the current song to fade out and the new song to fade in, `0.0` switches immediately.
+    ///
+    /// # Panics
+    ///
+    /// - When asset failed loading.
+    #[inline]
+    pub fn play(&self, path: &str, crossfade: f32) {
+        self.ctx
+            .write(|ctx| ctx.music_player.playlist_active = false);
+
+        self.switch_to(path, crossfade, true, false);
+    }
+
+    /// Play a song from where it was when another song replaced it, and keep looping it.
+    ///
+    /// Useful for returning to the theme of an area after a battle.
+    /// Plays the song from the start when it wasn't played before.
+    /// Does nothing when the song is already playing.
+    /// Stops the playlist, see [`Self::resume_playlist`] to continue it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Asset path of the audio file, see [`Context`] for more information about asset loading and storing.
+    /// * `crossfade` - Seconds it takes for the current song to fade out and the new song to fade in, `0.0` switches immediately.
+    ///
+    /// # Panics
+    ///
+    /// - When asset failed loading.
+    #[inline]
+    pub fn resume(&self, path: &str, crossfade: f32) {
+        self.ctx
+            .write(|ctx| ctx.music_player.playlist_active = false);
+
+        self.switch_to(path, crossfade, true, true);
+    }
+
+    /// Play songs one after another, starting over after the last song.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - Asset paths of the audio files, see [`Context`] for more information about asset loading and storing.
+    /// * `shuffle` - Whether to play the songs in a random order, which is reshuffled every time the playlist starts over.
+    /// * `crossfade` - Seconds it takes for the current song to fade out and the first song of the playlist to fade in, `0.0` switches immediately.
+    ///
+    /// # Panics
+    ///
+    /// - When `paths` is empty.
+    /// - When asset failed loading.
+    #[inline]
+    pub fn play_playlist(&self, paths: &[&str], shuffle: bool, crossfade: f32) {
+        assert!(!paths.is_empty(), "Playlist must contain at least one song");
+
+        let song = self.ctx.write(|ctx| {
+            ctx.music_player
+                .set_playlist(paths.iter().map(|&path| path.to_owned()).collect(), shuffle);
+            ctx.music_player.playlist_active = true;
+
+            ctx.music_player
+                .playlist_song()
+                .map(ToOwned::to_owned)
+                .expect("Playlist is not empty")
+        });
+
+        self.switch_to(&song, crossfade, false, false);
+    }
+
+    /// Continue the playlist after it's stopped by playing another song.
+    ///
+    /// The song of the playlist that was playing is resumed from where it was.
+    /// Does nothing when there's no playlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `crossfade` - Seconds it takes for the current song to fade out and the playlist song to fade in, `0.0` switches immediately.
+    #[inline]
+    pub fn resume_playlist(&self, crossfade: f32) {
+        let song = self.ctx.write(|ctx| {
+            let song = ctx.music_player.playlist_song().map(ToOwned::to_owned);
+            ctx.music_player.playlist_active = song.is_some();
+
+            song
+        });
+
+        if let Some(song) = song {
+            self.switch_to(&song, crossfade, false, true);
+        }
+    }
+
+    /// Skip to the next song of the playlist.
+    ///
+    /// Does nothing when there's no playlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `crossfade` - Seconds it takes for the current song to fade out and the next song to fade in, `0.0` switches immediately.
+    #[inline]
+    pub fn next(&self, crossfade: f32) {
+        let (song, current) = self.ctx.write(|ctx| {
+            let song = ctx.music_player.advance_playlist().map(ToOwned::to_owned);
+            ctx.music_player.playlist_active = song.is_some();
+
+            // Always play from the start, also when the playlist contains a single song
+            let current = song
+                .is_some()
+                .then(|| ctx.music_player.current.take())
+                .flatten();
+
+            (song, current)
+        });
+
+        if let Some((path, handle)) = current {
+            self.remember_and_stop(path, handle, crossfade);
+        }
+
+        if let Some(song) = song {
+            self.switch_to(&song, crossfade, false, false);
+        }
+    }
+
+    /// Fade out the song that's playing.
+    ///
+    /// Its position is remembered so it can be continued with [`Self::resume`] or [`Self::resume_playlist`].
+    ///
+    /// # Arguments
+    ///
+    /// * `fade_out` - Seconds it takes for the volume to fade to silence, `0.0` stops immediately.
+    #[inline]
+    pub fn stop(&self, fade_out: f32) {
+        let current = self.ctx.write(|ctx| {
+            ctx.music_player.playlist_active = false;
+
+            ctx.music_player.current.take()
+        });
+
+        if let Some((path, handle)) = current {
+            self.remember_and_stop(path, handle, fade_out);
+        }
+    }
+
+    /// Asset path of the song that's playing.
+    ///
+    /// # Returns
+    ///
+    /// - `None` when no song is playing.
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Option<String> {
+        self.ctx.read(|ctx| {
+            ctx.music_player
+                .current
+                .as_ref()
+                .map(|(path, _)| path.clone())
+        })
+    }
+
+    /// Fade out the current song and fade in another one.
+    fn switch_to(&self, path: &str, crossfade: f32, looping: bool, resume: bool) {
+        // Don't restart the song that's already playing
+        let current = self.ctx.read(|ctx| ctx.music_player.current.clone());
+        if let Some((current_path, handle)) = current {
+            if current_path == path && self.ctx.sound(handle).is_playing() {
+                return;
+            }
+
+            self.remember_and_stop(current_path, handle, crossfade);
+        }
+
+        // Start silent so it can fade in
+        let mut music = self.ctx.music(path).on_track(MUSIC_TRACK);
+        if crossfade > 0.0 {
+            music = music.with_volume(0.0);
+        }
+        if looping {
+            music = music.with_loop();
+        }
+        let handle = music.play();
+
+        let sound = self.ctx.sound(handle);
+
+        // Continue where the song was, or forget it when playing from the start
+        let position = self
+            .ctx
+            .write(|ctx| ctx.music_player.positions.remove(path));
+        if let Some(position) = position.filter(|_| resume) {
+            sound.seek_to(position);
+        }
+
+        // Fade in
+        if crossfade > 0.0 {
+            sound.set_volume(1.0, crossfade);
+        }
+
+        self.ctx
+            .write(|ctx| ctx.music_player.current = Some((path.to_owned(), handle)));
+    }
+
+    /// Store the position of a song and fade it out.
+    fn remember_and_stop(&self, path: String, handle: SoundHandle, fade_out: f32) {
+        let sound = self.ctx.sound(handle);
+
+        if let Some(position) = sound.position() {
+            self.ctx
+                .write(|ctx| ctx.music_player.positions.insert(path, position));
+        }
+
+        sound.stop(fade_out);
+    }
+}
+
+/// Music player methods.
+impl Context {
+    /// Control the background music, crossfading between songs.
+    ///
+    /// Check the [`MusicPlayerContext`] documentation for the options available.
+    ///
+    /// # Returns
+    ///
+    /// - A helper struct allowing you to play songs, playlists and to resume songs.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chuot::Context;
+    ///
+    /// # struct Empty { in_battle: bool } impl Empty {
+    /// // In `Game::update` trait implementation
+    /// // ..
+    /// fn update(&mut self, ctx: Context) {
+    ///   if self.in_battle {
+    ///     // Crossfade to the battle theme in half a second
+    ///     ctx.music_player().play("battle", 0.5);
+    ///   } else {
+    ///     // Continue the theme of the area where it was before the battle
+    ///     ctx.music_player().resume("forest", 2.0);
+    ///   }
+    /// }
+    /// # }
+    #[inline(always)]
+    #[must_use]
+    pub const fn music_player(&self) -> MusicPlayerContext<'_> {
+        MusicPlayerContext { ctx: self }
+    }
+
+    /// Play the next song of the playlist when the current one finished.
+    ///
+    /// Must be done in the update tick.
+    pub(crate) fn update_music_player(&self) {
+        let finished = self.read(|ctx| {
+            ctx.music_player.playlist_active
+                && ctx
+                    .music_player
+                    .current
+                    .as_ref()
+                    .is_some_and(|(_, handle)| {
+                        ctx.sounds
+                            .get(handle)
+                            .is_none_or(|sound| sound.state() == PlaybackState::Stopped)
+                    })
+        });
+
+        if finished {
+            self.music_player().next(0.0);
+        }
+    }
+}
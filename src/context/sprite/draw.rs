@@ -1,5 +1,7 @@
 //! Different implementations for drawing a sprite.
 
+use glam::Vec2;
+
 use super::SpriteContext;
 use crate::context::{
     extensions::{
@@ -28,8 +30,12 @@ impl<'shader, L: LoadMethod, O: Pivot, D: Shader<'shader>, C: IsUiCamera>
     #[inline]
     pub fn draw(self) {
         self.ctx.write(|ctx| {
-            let (sprite, affine_matrix) =
-                ctx.sprite_with_base_affine_matrix(&self.load, C::is_ui_camera(), self.pivot);
+            let (sprite, affine_matrix) = ctx.sprite_with_base_affine_matrix(
+                &self.load,
+                C::is_ui_camera(),
+                self.pivot,
+                (0.0, 0.0),
+            );
 
             // Ensure the shader is loaded before using it
             let shader = ctx.sprite_load_shader_if_not_exists(self.shader);
@@ -102,12 +108,12 @@ impl<'shader, L: LoadMethod, O: Pivot, D: Shader<'shader>, C: IsUiCamera>
     #[inline]
     pub fn draw(self) {
         self.ctx.write(|ctx| {
-            let (sprite, mut affine_matrix) =
-                ctx.sprite_with_base_affine_matrix(&self.load, C::is_ui_camera(), self.pivot);
-
-            // Translate the coordinates
-            affine_matrix.translation.x += self.translation.x;
-            affine_matrix.translation.y += self.translation.y;
+            let (sprite, affine_matrix) = ctx.sprite_with_base_affine_matrix(
+                &self.load,
+                C::is_ui_camera(),
+                self.pivot,
+                (self.translation.x, self.translation.y),
+            );
 
             // Ensure the shader is loaded before using it
             let shader = ctx.sprite_load_shader_if_not_exists(self.shader);
@@ -180,21 +186,25 @@ impl<'shader, L: LoadMethod, O: Pivot, D: Shader<'shader>, C: IsUiCamera>
     #[inline]
     pub fn draw(self) {
         self.ctx.write(|ctx| {
-            let (sprite, mut affine_matrix) =
-                ctx.sprite_with_base_affine_matrix(&self.load, C::is_ui_camera(), self.pivot);
-
-            // Translate the coordinates
-            affine_matrix.translation.x += crate::math::lerp(
+            // Interpolate the coordinates
+            let x = crate::math::lerp(
                 self.previous_translation.previous_x,
                 self.translation.x,
                 ctx.blending_factor,
             );
-            affine_matrix.translation.y += crate::math::lerp(
+            let y = crate::math::lerp(
                 self.previous_translation.previous_y,
                 self.translation.y,
                 ctx.blending_factor,
             );
 
+            let (sprite, affine_matrix) = ctx.sprite_with_base_affine_matrix(
+                &self.load,
+                C::is_ui_camera(),
+                self.pivot,
+                (x, y),
+            );
+
             // Ensure the shader is loaded before using it
             let shader = ctx.sprite_load_shader_if_not_exists(self.shader);
 
@@ -724,28 +734,27 @@ impl<L: LoadMethod, O: Pivot, C: IsUiCamera>
             // Push the instance if the texture is already uploaded
             let sprite = &self.load.sprite(ctx);
 
-            // Get the camera to draw the sprite with
-            let camera = ctx.camera(C::is_ui_camera());
-            let offset_x = camera.offset_x();
-            let offset_y = camera.offset_y();
+            // Get the camera transformation to draw the sprite with
+            let camera = ctx.camera(C::is_ui_camera()).affine_matrix();
 
             // Get the generic pivot position
             let (pivot_x, pivot_y) = self.pivot.pivot_value(sprite.pivot_x(), sprite.pivot_y());
 
-            // Create the affine matrix
-            let affine_matrix = sprite.affine_matrix(
-                self.translation.x + offset_x,
-                self.translation.y + offset_y,
-                0.0,
-                0.0,
-                0.0,
-                false,
-                self.rotation.value(),
-                self.scaling.scale_x,
-                self.scaling.scale_y,
-                pivot_x,
-                pivot_y,
-            );
+            // Create the affine matrix, transformed by the camera
+            let affine_matrix = camera
+                * sprite.affine_matrix(
+                    self.translation.x,
+                    self.translation.y,
+                    0.0,
+                    0.0,
+                    0.0,
+                    false,
+                    self.rotation.value(),
+                    self.scaling.scale_x,
+                    self.scaling.scale_y,
+                    pivot_x,
+                    pivot_y,
+                );
 
             // Ensure the shader is loaded before using it
             let shader = ctx.sprite_load_shader_if_not_exists(self.shader);
@@ -802,28 +811,27 @@ impl<L: LoadMethod, O: Pivot, C: IsUiCamera>
             // Push the instance if the texture is already uploaded
             let sprite = &self.load.sprite(ctx);
 
-            // Get the camera to draw the sprite with
-            let camera = ctx.camera(C::is_ui_camera());
-            let offset_x = camera.offset_x();
-            let offset_y = camera.offset_y();
+            // Get the camera transformation to draw the sprite with
+            let camera = ctx.camera(C::is_ui_camera()).affine_matrix();
 
             // Get the generic pivot position
             let (pivot_x, pivot_y) = self.pivot.pivot_value(sprite.pivot_x(), sprite.pivot_y());
 
-            // Create the affine matrix
-            let affine_matrix = sprite.affine_matrix(
-                self.translation.x + offset_x,
-                self.translation.y + offset_y,
-                0.0,
-                0.0,
-                0.0,
-                false,
-                self.rotation.value(),
-                self.scaling.scale_x,
-                self.scaling.scale_y,
-                pivot_x,
-                pivot_y,
-            );
+            // Create the affine matrix, transformed by the camera
+            let affine_matrix = camera
+                * sprite.affine_matrix(
+                    self.translation.x,
+                    self.translation.y,
+                    0.0,
+                    0.0,
+                    0.0,
+                    false,
+                    self.rotation.value(),
+                    self.scaling.scale_x,
+                    self.scaling.scale_y,
+                    pivot_x,
+                    pivot_y,
+                );
 
             // Ensure the shader is loaded before using it
             let shader = ctx.sprite_load_shader_if_not_exists(self.shader);
@@ -832,10 +840,10 @@ impl<L: LoadMethod, O: Pivot, C: IsUiCamera>
             ctx.graphics.extend_instances(
                 shader,
                 translations.map(Into::into).map(|(x_offset, y_offset)| {
-                    // Copy the matrix
+                    // Copy the matrix and offset it in the space of the camera
                     let mut affine_matrix_with_offset = affine_matrix;
-                    affine_matrix_with_offset.translation.x += x_offset;
-                    affine_matrix_with_offset.translation.y += y_offset;
+                    affine_matrix_with_offset.translation +=
+                        camera.matrix2 * Vec2::new(x_offset, y_offset);
 
                     (
                         affine_matrix_with_offset,
@@ -865,28 +873,27 @@ impl<L: LoadMethod, O: Pivot, C: IsUiCamera>
             // Push the instance if the texture is already uploaded
             let sprite = &self.load.sprite(ctx);
 
-            // Get the camera to draw the sprite with
-            let camera = ctx.camera(C::is_ui_camera());
-            let offset_x = camera.offset_x();
-            let offset_y = camera.offset_y();
+            // Get the camera transformation to draw the sprite with
+            let camera = ctx.camera(C::is_ui_camera()).affine_matrix();
 
             // Get the generic pivot position
             let (pivot_x, pivot_y) = self.pivot.pivot_value(sprite.pivot_x(), sprite.pivot_y());
 
-            // Create the affine matrix
-            let affine_matrix = sprite.affine_matrix(
-                self.translation.x + offset_x,
-                self.translation.y + offset_y,
-                self.previous_translation.previous_x + offset_x,
-                self.previous_translation.previous_y + offset_y,
-                ctx.blending_factor,
-                true,
-                self.rotation.value(),
-                self.scaling.scale_x,
-                self.scaling.scale_y,
-                pivot_x,
-                pivot_y,
-            );
+            // Create the affine matrix, transformed by the camera
+            let affine_matrix = camera
+                * sprite.affine_matrix(
+                    self.translation.x,
+                    self.translation.y,
+                    self.previous_translation.previous_x,
+                    self.previous_translation.previous_y,
+                    ctx.blending_factor,
+                    true,
+                    self.rotation.value(),
+                    self.scaling.scale_x,
+                    self.scaling.scale_y,
+                    pivot_x,
+                    pivot_y,
+                );
 
             // Ensure the shader is loaded before using it
             let shader = ctx.sprite_load_shader_if_not_exists(self.shader);
@@ -943,28 +950,27 @@ impl<L: LoadMethod, O: Pivot, C: IsUiCamera>
             // Push the instance if the texture is already uploaded
             let sprite = &self.load.sprite(ctx);
 
-            // Get the camera to draw the sprite with
-            let camera = ctx.camera(C::is_ui_camera());
-            let offset_x = camera.offset_x();
-            let offset_y = camera.offset_y();
+            // Get the camera transformation to draw the sprite with
+            let camera = ctx.camera(C::is_ui_camera()).affine_matrix();
 
             // Get the generic pivot position
             let (pivot_x, pivot_y) = self.pivot.pivot_value(sprite.pivot_x(), sprite.pivot_y());
 
-            // Create the affine matrix
-            let affine_matrix = sprite.affine_matrix(
-                self.translation.x + offset_x,
-                self.translation.y + offset_y,
-                self.previous_translation.previous_x + offset_x,
-                self.previous_translation.previous_y + offset_y,
-                ctx.blending_factor,
-                true,
-                self.rotation.value(),
-                self.scaling.scale_x,
-                self.scaling.scale_y,
-                pivot_x,
-                pivot_y,
-            );
+            // Create the affine matrix, transformed by the camera
+            let affine_matrix = camera
+                * sprite.affine_matrix(
+                    self.translation.x,
+                    self.translation.y,
+                    self.previous_translation.previous_x,
+                    self.previous_translation.previous_y,
+                    ctx.blending_factor,
+                    true,
+                    self.rotation.value(),
+                    self.scaling.scale_x,
+                    self.scaling.scale_y,
+                    pivot_x,
+                    pivot_y,
+                );
 
             // Ensure the shader is loaded before using it
             let shader = ctx.sprite_load_shader_if_not_exists(self.shader);
@@ -973,10 +979,10 @@ impl<L: LoadMethod, O: Pivot, C: IsUiCamera>
             ctx.graphics.extend_instances(
                 shader,
                 translations.map(Into::into).map(|(x_offset, y_offset)| {
-                    // Copy the matrix
+                    // Copy the matrix and offset it in the space of the camera
                     let mut affine_matrix_with_offset = affine_matrix;
-                    affine_matrix_with_offset.translation.x += x_offset;
-                    affine_matrix_with_offset.translation.y += y_offset;
+                    affine_matrix_with_offset.translation +=
+                        camera.matrix2 * Vec2::new(x_offset, y_offset);
 
                     (
                         affine_matrix_with_offset,
@@ -759,13 +759,14 @@ impl Context {
 
 /// Helper functions to reduce code duplication.
 impl ContextInner {
-    /// Get the sprite with it's base offset calculated from the camera and its internal offset.
+    /// Get the sprite with it's base matrix calculated from the translation, the camera and its internal offset.
     #[inline]
     fn sprite_with_base_affine_matrix<L, P>(
         &mut self,
         load: &L,
         is_ui_camera: bool,
         pivot: P,
+        (x, y): (f32, f32),
     ) -> (Rc<Sprite>, Affine2)
     where
         L: LoadMethod,
@@ -777,22 +778,21 @@ impl ContextInner {
         let (pivot_x, pivot_y) = pivot.pivot_value(sprite.pivot_x(), sprite.pivot_y());
 
         // Get the sprite offset
-        let (mut sprite_x, mut sprite_y) = sprite.pivot_offset(pivot_x, pivot_y);
+        let (sprite_x, sprite_y) = sprite.pivot_offset(pivot_x, pivot_y);
 
-        // Offset the sprite with the camera
-        let camera = self.camera(is_ui_camera);
-        sprite_x += camera.offset_x();
-        sprite_y += camera.offset_y();
-
-        // Create the affine matrix
-        let affine_matrix = Affine2::from_translation((sprite_x, sprite_y).into());
+        // Create the affine matrix, transformed by the camera
+        let affine_matrix = self.camera(is_ui_camera).affine_matrix()
+            * Affine2::from_translation((sprite_x + x, sprite_y + y).into());
 
         (sprite, affine_matrix)
     }
 
     /// Load the shader.
     #[inline]
-    fn sprite_load_shader_if_not_exists<'shader, S>(&mut self, shader: S) -> Option<&'shader str>
+    pub(crate) fn sprite_load_shader_if_not_exists<'shader, S>(
+        &mut self,
+        shader: S,
+    ) -> Option<&'shader str>
     where
         S: Shader<'shader>,
     {
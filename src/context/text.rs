@@ -5,6 +5,10 @@ use std::marker::PhantomData;
 use super::extensions::{
     Empty,
     camera::{IsUiCamera, MainCamera, UiCamera},
+    pivot::{Pivot, Pivoting},
+    rotate::{Rotate, Rotation},
+    scale::{Scale, Scaling},
+    shader::{ApplyShader, Shader},
     translate::{PreviousTranslation, Translate, TranslatePrevious, Translation},
 };
 use crate::{Context, assets::loadable::sprite::SpritePivot};
@@ -14,7 +18,18 @@ use crate::{Context, assets::loadable::sprite::SpritePivot};
 /// Must call [`Self::draw`] to finish drawing.
 ///
 /// Used by [`crate::Context::text`].
-pub struct TextContext<'font, 'text, 'ctx, T, P, C> {
+pub struct TextContext<
+    'font,
+    'text,
+    'ctx,
+    T = Empty,
+    P = Empty,
+    R = Empty,
+    S = Empty,
+    O = Empty,
+    D = Empty,
+    C = Empty,
+> {
     /// Path of the font to draw.
     pub(crate) font: &'font str,
     /// Reference to the context the text will draw in when finished.
@@ -25,12 +40,31 @@ pub struct TextContext<'font, 'text, 'ctx, T, P, C> {
     pub(crate) translation: T,
     /// Possible previous translation implementation, determined by type.
     pub(crate) previous_translation: P,
+    /// Possible rotation implementation, determined by type.
+    pub(crate) rotation: R,
+    /// Possible scaling implementation, determined by type.
+    pub(crate) scaling: S,
+    /// Possible pivot/offset implementation, determined by type.
+    pub(crate) pivot: O,
+    /// Possible custom shader, determined by type.
+    pub(crate) shader: D,
     /// Generic types without any concrete fields.
     pub(crate) phantom: PhantomData<C>,
 }
 
-impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
-    TextContext<'font, 'text, 'ctx, T, P, C>
+impl<
+    'font,
+    'text,
+    'ctx,
+    'shader,
+    T: Translate,
+    P: TranslatePrevious,
+    R: Rotate,
+    S: Scale,
+    O: Pivot,
+    D: Shader<'shader>,
+    C: IsUiCamera,
+> TextContext<'font, 'text, 'ctx, T, P, R, S, O, D, C>
 {
     /// Only move the horizontal position.
     ///
@@ -39,7 +73,10 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
     /// * `x` - Horizontal position on the buffer in pixels.
     #[inline(always)]
     #[must_use]
-    pub fn translate_x(self, x: f32) -> TextContext<'font, 'text, 'ctx, Translation, P, C> {
+    pub fn translate_x(
+        self,
+        x: f32,
+    ) -> TextContext<'font, 'text, 'ctx, Translation, P, R, S, O, D, C> {
         self.translate_impl((x, 0.0))
     }
 
@@ -50,7 +87,10 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
     /// * `y` - Vertical position on the buffer in pixels.
     #[inline(always)]
     #[must_use]
-    pub fn translate_y(self, y: f32) -> TextContext<'font, 'text, 'ctx, Translation, P, C> {
+    pub fn translate_y(
+        self,
+        y: f32,
+    ) -> TextContext<'font, 'text, 'ctx, Translation, P, R, S, O, D, C> {
         self.translate_impl((0.0, y))
     }
 
@@ -64,7 +104,7 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
     pub fn translate(
         self,
         position: impl Into<(f32, f32)>,
-    ) -> TextContext<'font, 'text, 'ctx, Translation, P, C> {
+    ) -> TextContext<'font, 'text, 'ctx, Translation, P, R, S, O, D, C> {
         self.translate_impl(position.into())
     }
 
@@ -88,7 +128,7 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
     pub fn translate_previous_x(
         self,
         previous_x: f32,
-    ) -> TextContext<'font, 'text, 'ctx, T, PreviousTranslation, C> {
+    ) -> TextContext<'font, 'text, 'ctx, T, PreviousTranslation, R, S, O, D, C> {
         self.translate_previous_impl((previous_x, 0.0))
     }
 
@@ -112,7 +152,7 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
     pub fn translate_previous_y(
         self,
         previous_y: f32,
-    ) -> TextContext<'font, 'text, 'ctx, T, PreviousTranslation, C> {
+    ) -> TextContext<'font, 'text, 'ctx, T, PreviousTranslation, R, S, O, D, C> {
         self.translate_previous_impl((0.0, previous_y))
     }
 
@@ -137,20 +177,164 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
     pub fn translate_previous(
         self,
         previous_position: impl Into<(f32, f32)>,
-    ) -> TextContext<'font, 'text, 'ctx, T, PreviousTranslation, C> {
+    ) -> TextContext<'font, 'text, 'ctx, T, PreviousTranslation, R, S, O, D, C> {
         self.translate_previous_impl(previous_position.into())
     }
 
+    /// Only scale the horizontal size of the whole text.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale_x` - Horizontal scale on the buffer. `-1.0` to flip.
+    #[inline(always)]
+    #[must_use]
+    pub fn scale_x(
+        self,
+        scale_x: f32,
+    ) -> TextContext<'font, 'text, 'ctx, T, P, R, Scaling, O, D, C> {
+        self.scale_impl((scale_x, 1.0))
+    }
+
+    /// Only scale the vertical size of the whole text.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale_y` - Vertical scale on the buffer. `-1.0` to flip.
+    #[inline(always)]
+    #[must_use]
+    pub fn scale_y(
+        self,
+        scale_y: f32,
+    ) -> TextContext<'font, 'text, 'ctx, T, P, R, Scaling, O, D, C> {
+        self.scale_impl((1.0, scale_y))
+    }
+
+    /// Scale the whole text around its pivot point.
+    ///
+    /// # Arguments
+    ///
+    /// * `(scale_x, scale_y)` - Scale tuple on the buffer.
+    #[inline]
+    #[must_use]
+    pub fn scale(
+        self,
+        scale: impl Into<(f32, f32)>,
+    ) -> TextContext<'font, 'text, 'ctx, T, P, R, Scaling, O, D, C> {
+        self.scale_impl(scale.into())
+    }
+
+    /// Rotate the whole text as a single unit.
+    ///
+    /// Rotation will always be applied before translation, this mean it will always rotate around the pivot point of the text.
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation` - Rotation in radians, will be applied using the algorithm passed in [`crate::config::Config::with_rotation_algorithm`].
+    #[inline]
+    #[must_use]
+    pub fn rotate(
+        self,
+        rotation: f32,
+    ) -> TextContext<'font, 'text, 'ctx, T, P, Rotation, S, O, D, C> {
+        let rotation = self.rotation.inner_rotate(rotation);
+
+        TextContext {
+            font: self.font,
+            ctx: self.ctx,
+            text: self.text,
+            translation: self.translation,
+            previous_translation: self.previous_translation,
+            scaling: self.scaling,
+            pivot: self.pivot,
+            shader: self.shader,
+            rotation,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Change the text pivot point to the top left of the text.
+    ///
+    /// The pivot point is the relative point to which the text is positioned.
+    /// When rotating or scaling text the pivot point will be the center of the transformation.
+    ///
+    /// This is the default.
+    ///
+    /// This is equivalent to `.pivot(0.0, 0.0)`.
+    #[inline]
+    #[must_use]
+    pub fn pivot_top_left(self) -> TextContext<'font, 'text, 'ctx, T, P, R, S, Pivoting, D, C> {
+        self.pivot_impl(SpritePivot::Start, SpritePivot::Start)
+    }
+
+    /// Change the text pivot point to the center of the text for both axes.
+    ///
+    /// The pivot point is the relative point to which the text is positioned.
+    /// When rotating or scaling text the pivot point will be the center of the transformation.
+    ///
+    /// The center is calculated from the longest line and the amount of lines.
+    ///
+    /// This is equivalent to `.pivot(0.5, 0.5)`.
+    #[inline]
+    #[must_use]
+    pub fn pivot_center(self) -> TextContext<'font, 'text, 'ctx, T, P, R, S, Pivoting, D, C> {
+        self.pivot_impl(SpritePivot::Center, SpritePivot::Center)
+    }
+
+    /// Change the text pivot point to an absolute offset of pixels from the top left.
+    ///
+    /// The pivot point is the relative point to which the text is positioned.
+    /// When rotating or scaling text the pivot point will be the center of the transformation.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset_x` - Absolute horizontal offset in pixels from the left of the text.
+    /// * `offset_y` - Absolute vertical offset in pixels from the top of the text.
+    #[inline]
+    #[must_use]
+    pub fn pivot_pixels(
+        self,
+        offset_x: f32,
+        offset_y: f32,
+    ) -> TextContext<'font, 'text, 'ctx, T, P, R, S, Pivoting, D, C> {
+        self.pivot_impl(SpritePivot::Pixels(offset_x), SpritePivot::Pixels(offset_y))
+    }
+
+    /// Change the text pivot point to a fraction of the size of the text from the top left.
+    ///
+    /// The pivot point is the relative point to which the text is positioned.
+    /// When rotating or scaling text the pivot point will be the center of the transformation.
+    ///
+    /// # Arguments
+    ///
+    /// * `fraction_x` - Fraction `(0.0 .. 1.0)` relative to the width of the longest line from the left of the text.
+    /// * `fraction_y` - Fraction `(0.0 .. 1.0)` relative to the height of all lines from the top of the text.
+    #[inline]
+    #[must_use]
+    pub fn pivot(
+        self,
+        fraction_x: f32,
+        fraction_y: f32,
+    ) -> TextContext<'font, 'text, 'ctx, T, P, R, S, Pivoting, D, C> {
+        self.pivot_impl(
+            SpritePivot::Fraction(fraction_x),
+            SpritePivot::Fraction(fraction_y),
+        )
+    }
+
     /// Use the UI camera instead of the regular game camera for transforming the drawable object.
     #[inline]
     #[must_use]
-    pub fn use_ui_camera(self) -> TextContext<'font, 'text, 'ctx, T, P, UiCamera> {
+    pub fn use_ui_camera(self) -> TextContext<'font, 'text, 'ctx, T, P, R, S, O, D, UiCamera> {
         TextContext {
             font: self.font,
             ctx: self.ctx,
             text: self.text,
             translation: self.translation,
             previous_translation: self.previous_translation,
+            rotation: self.rotation,
+            scaling: self.scaling,
+            pivot: self.pivot,
+            shader: self.shader,
             phantom: PhantomData,
         }
     }
@@ -158,13 +342,46 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
     /// Use the regular game camera instead of the UI camera for transforming the drawable object.
     #[inline]
     #[must_use]
-    pub fn use_main_camera(self) -> TextContext<'font, 'text, 'ctx, T, P, MainCamera> {
+    pub fn use_main_camera(self) -> TextContext<'font, 'text, 'ctx, T, P, R, S, O, D, MainCamera> {
+        TextContext {
+            font: self.font,
+            ctx: self.ctx,
+            text: self.text,
+            translation: self.translation,
+            previous_translation: self.previous_translation,
+            rotation: self.rotation,
+            scaling: self.scaling,
+            pivot: self.pivot,
+            shader: self.shader,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Choose a custom shader to draw all glyphs of the text with.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Asset path of the custom shader asset, will be initialized when not loaded yet.
+    ///
+    /// # Panics
+    ///
+    /// - When shader asset failed loading.
+    #[inline]
+    #[must_use]
+    pub fn shader<'path>(
+        self,
+        path: &'path str,
+    ) -> TextContext<'font, 'text, 'ctx, T, P, R, S, O, ApplyShader<'path>, C> {
         TextContext {
             font: self.font,
             ctx: self.ctx,
             text: self.text,
             translation: self.translation,
             previous_translation: self.previous_translation,
+            rotation: self.rotation,
+            scaling: self.scaling,
+            pivot: self.pivot,
+            shader: ApplyShader::new(path),
             phantom: PhantomData,
         }
     }
@@ -175,7 +392,7 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
     fn translate_impl(
         self,
         position: (f32, f32),
-    ) -> TextContext<'font, 'text, 'ctx, Translation, P, C> {
+    ) -> TextContext<'font, 'text, 'ctx, Translation, P, R, S, O, D, C> {
         let translation = self.translation.inner_translate(position);
 
         TextContext {
@@ -184,6 +401,10 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
             text: self.text,
             translation,
             previous_translation: self.previous_translation,
+            rotation: self.rotation,
+            scaling: self.scaling,
+            pivot: self.pivot,
+            shader: self.shader,
             phantom: PhantomData,
         }
     }
@@ -194,7 +415,7 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
     fn translate_previous_impl(
         self,
         previous_position: (f32, f32),
-    ) -> TextContext<'font, 'text, 'ctx, T, PreviousTranslation, C> {
+    ) -> TextContext<'font, 'text, 'ctx, T, PreviousTranslation, R, S, O, D, C> {
         let previous_translation = self
             .previous_translation
             .inner_translate_previous(previous_position);
@@ -205,115 +426,120 @@ impl<'font, 'text, 'ctx, T: Translate, P: TranslatePrevious, C: IsUiCamera>
             text: self.text,
             translation: self.translation,
             previous_translation,
+            rotation: self.rotation,
+            scaling: self.scaling,
+            pivot: self.pivot,
+            shader: self.shader,
             phantom: PhantomData,
         }
     }
-}
 
-/// Nothing.
-impl<C: IsUiCamera> TextContext<'_, '_, '_, Empty, Empty, C> {
-    /// Draw the text to the screen at the zero coordinate of the camera.
-    ///
-    /// Text glyphs and other sprites that are drawn last are always shown on top of sprites that are drawn earlier.
-    ///
-    /// # Panics
-    ///
-    /// - When asset failed loading.
+    /// Perform the scaling with the type.
     #[inline]
-    pub fn draw(self) {
-        // TODO: optimize
+    #[must_use]
+    fn scale_impl(
+        self,
+        scale: (f32, f32),
+    ) -> TextContext<'font, 'text, 'ctx, T, P, R, Scaling, O, D, C> {
+        let scaling = self.scaling.inner_scale(scale);
+
         TextContext {
             font: self.font,
             ctx: self.ctx,
             text: self.text,
-            translation: Translation::default(),
+            translation: self.translation,
             previous_translation: self.previous_translation,
-            phantom: self.phantom,
+            rotation: self.rotation,
+            pivot: self.pivot,
+            shader: self.shader,
+            scaling,
+            phantom: PhantomData,
         }
-        .draw();
     }
-}
 
-/// Only translation.
-impl<C: IsUiCamera> TextContext<'_, '_, '_, Translation, Empty, C> {
-    /// Draw the text to the screen.
-    ///
-    /// Text glyphs and other sprites that are drawn last are always shown on top of sprites that are drawn earlier.
-    ///
-    /// # Panics
-    ///
-    /// - When asset failed loading.
+    /// Set the pivot with the type.
     #[inline]
-    pub fn draw(self) {
-        // TODO: reduce duplicate code
+    #[must_use]
+    fn pivot_impl(
+        self,
+        pivot_x: SpritePivot,
+        pivot_y: SpritePivot,
+    ) -> TextContext<'font, 'text, 'ctx, T, P, R, S, Pivoting, D, C> {
+        TextContext {
+            font: self.font,
+            ctx: self.ctx,
+            text: self.text,
+            translation: self.translation,
+            previous_translation: self.previous_translation,
+            rotation: self.rotation,
+            scaling: self.scaling,
+            shader: self.shader,
+            pivot: Pivoting::new(pivot_x, pivot_y),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Draw every glyph of the text with the same transformation applied to the whole text block.
+    ///
+    /// Each glyph is offset from the pivot of the text block, so rotating and scaling transforms the text as a single unit.
+    fn draw_impl(self, (x, y): (f32, f32), (previous_x, previous_y): (f32, f32), blend: bool) {
         self.ctx.write(|ctx| {
             // Push the instance if the texture is already uploaded
             let font = ctx.font(self.font);
 
-            // Get the camera to draw the sprite with
-            let camera = ctx.camera(C::is_ui_camera());
-            let offset_x = camera.offset_x();
-            let offset_y = camera.offset_y();
+            // Ensure the shader is loaded before using it
+            let shader = ctx.sprite_load_shader_if_not_exists(self.shader);
+
+            // Get the camera transformation to draw the text with
+            let camera = ctx.camera(C::is_ui_camera()).affine_matrix();
+
+            // Get the transformations, using the defaults when not set
+            let rotation = self.rotation.inner_rotate(0.0).value();
+            let Scaling { scale_x, scale_y } = self.scaling.inner_scale((1.0, 1.0));
 
-            // Put the start position back 1 glyph since the first action is to move the cursor
-            let start_x = self.translation.x - font.metadata.glyph_width;
-            let mut x = start_x;
-            let mut y = self.translation.y;
+            // The pivot is relative to the whole text block instead of a single glyph
+            let (pivot_x, pivot_y) = self
+                .pivot
+                .pivot_value(SpritePivot::Start, SpritePivot::Start);
+            let (width, height) = font.text_size(self.text);
+            let text_offset_x = pivot_x.pivot(width);
+            let text_offset_y = pivot_y.pivot(height);
 
             // Draw each character from the string
-            self.text.chars().for_each(|ch| {
-                let char_index = ch as usize;
-
-                // Move the cursor
-                x += font.metadata.glyph_width;
-
-                // Don't draw characters that are not in the picture
-                if char_index < font.metadata.first_char || char_index > font.metadata.last_char {
-                    if ch == '\n' {
-                        x = start_x;
-                        y += font.metadata.glyph_height;
-                    } else if ch == '\t' {
-                        x += font.metadata.glyph_width * 3.0;
-                    }
-                    return;
-                }
-
-                // The sub rectangle offset of the character is based on the starting character and counted using the ASCII index
-                let char_offset = char_index - font.metadata.first_char;
-
-                // Setup the sprite for the glyph
-                let sprite = font.sprites[char_offset];
-
-                // Create the affine matrix
-                let affine_matrix = sprite.affine_matrix(
-                    x + offset_x,
-                    y + offset_y,
-                    0.0,
-                    0.0,
-                    0.0,
-                    false,
-                    0.0,
-                    1.0,
-                    1.0,
-                    SpritePivot::Start,
-                    SpritePivot::Start,
-                );
+            for (glyph_x, glyph_y, sprite) in font.glyph_positions(self.text) {
+                // Create the affine matrix, with the glyph position in the text block as the pivot so it transforms around the pivot of the text
+                let affine_matrix = camera
+                    * sprite.affine_matrix(
+                        x,
+                        y,
+                        previous_x,
+                        previous_y,
+                        ctx.blending_factor,
+                        blend,
+                        rotation,
+                        scale_x,
+                        scale_y,
+                        SpritePivot::Pixels(-(glyph_x + text_offset_x)),
+                        SpritePivot::Pixels(-(glyph_y + text_offset_y)),
+                    );
 
                 // Push the graphics
                 ctx.graphics.push_instance(
-                    None,
+                    shader,
                     affine_matrix,
                     sprite.sub_rectangle,
                     sprite.texture,
                 );
-            });
+            }
         });
     }
 }
 
-/// Translation and previous translation.
-impl<C: IsUiCamera> TextContext<'_, '_, '_, Translation, PreviousTranslation, C> {
-    /// Draw the text smoothly to the screen, interpolating the position in the render step.
+/// Nothing.
+impl<'shader, R: Rotate, S: Scale, O: Pivot, D: Shader<'shader>, C: IsUiCamera>
+    TextContext<'_, '_, '_, Empty, Empty, R, S, O, D, C>
+{
+    /// Draw the text to the screen at the zero coordinate of the camera.
     ///
     /// Text glyphs and other sprites that are drawn last are always shown on top of sprites that are drawn earlier.
     ///
@@ -322,80 +548,49 @@ impl<C: IsUiCamera> TextContext<'_, '_, '_, Translation, PreviousTranslation, C>
     /// - When asset failed loading.
     #[inline]
     pub fn draw(self) {
-        // TODO: reduce duplicate code
-        self.ctx.write(|ctx| {
-            // Push the instance if the texture is already uploaded
-            let font = ctx.font(self.font);
+        self.draw_impl((0.0, 0.0), (0.0, 0.0), false);
+    }
+}
 
-            // Get the camera to draw the sprite with
-            let camera = ctx.camera(C::is_ui_camera());
-            let offset_x = camera.offset_x();
-            let offset_y = camera.offset_y();
-
-            // Interpolate with the previous location for smooth rendering
-            let mut x = crate::math::lerp(
-                self.previous_translation.previous_x,
-                self.translation.x,
-                ctx.blending_factor,
-            );
-            let mut y = crate::math::lerp(
-                self.previous_translation.previous_y,
-                self.translation.y,
-                ctx.blending_factor,
-            );
-
-            // Put the start position back 1 glyph since the first action is to move the cursor
-            let start_x = x - font.metadata.glyph_width;
-            x = start_x;
+/// Only translation.
+impl<'shader, R: Rotate, S: Scale, O: Pivot, D: Shader<'shader>, C: IsUiCamera>
+    TextContext<'_, '_, '_, Translation, Empty, R, S, O, D, C>
+{
+    /// Draw the text to the screen.
+    ///
+    /// Text glyphs and other sprites that are drawn last are always shown on top of sprites that are drawn earlier.
+    ///
+    /// # Panics
+    ///
+    /// - When asset failed loading.
+    #[inline]
+    pub fn draw(self) {
+        let Translation { x, y } = self.translation;
 
-            // Draw each character from the string
-            self.text.chars().for_each(|ch| {
-                let char_index = ch as usize;
-
-                // Move the cursor
-                x += font.metadata.glyph_width;
-
-                // Don't draw characters that are not in the picture
-                if char_index < font.metadata.first_char || char_index > font.metadata.last_char {
-                    if ch == '\n' {
-                        x = start_x;
-                        y += font.metadata.glyph_height;
-                    } else if ch == '\t' {
-                        x += font.metadata.glyph_width * 3.0;
-                    }
-                    return;
-                }
-
-                // The sub rectangle offset of the character is based on the starting character and counted using the ASCII index
-                let char_offset = char_index - font.metadata.first_char;
-
-                // Setup the sprite for the glyph
-                let sprite = font.sprites[char_offset];
-
-                // Create the affine matrix
-                let affine_matrix = sprite.affine_matrix(
-                    x + offset_x,
-                    y + offset_y,
-                    0.0,
-                    0.0,
-                    0.0,
-                    false,
-                    0.0,
-                    1.0,
-                    1.0,
-                    SpritePivot::Start,
-                    SpritePivot::Start,
-                );
+        self.draw_impl((x, y), (x, y), false);
+    }
+}
 
-                // Push the graphics
-                ctx.graphics.push_instance(
-                    None,
-                    affine_matrix,
-                    sprite.sub_rectangle,
-                    sprite.texture,
-                );
-            });
-        });
+/// Translation and previous translation.
+impl<'shader, R: Rotate, S: Scale, O: Pivot, D: Shader<'shader>, C: IsUiCamera>
+    TextContext<'_, '_, '_, Translation, PreviousTranslation, R, S, O, D, C>
+{
+    /// Draw the text smoothly to the screen, interpolating the position in the render step.
+    ///
+    /// Text glyphs and other sprites that are drawn last are always shown on top of sprites that are drawn earlier.
+    ///
+    /// # Panics
+    ///
+    /// - When asset failed loading.
+    #[inline]
+    pub fn draw(self) {
+        let Translation { x, y } = self.translation;
+        let PreviousTranslation {
+            previous_x,
+            previous_y,
+        } = self.previous_translation;
+
+        self.draw_impl((x, y), (previous_x, previous_y), true);
     }
 }
 
@@ -424,13 +619,17 @@ impl Context {
         &self,
         font: &'font str,
         text: &'text str,
-    ) -> TextContext<'font, 'text, '_, Empty, Empty, MainCamera> {
+    ) -> TextContext<'font, 'text, '_, Empty, Empty, Empty, Empty, Empty, Empty, MainCamera> {
         TextContext {
             font,
             ctx: self,
             text,
             translation: Empty,
             previous_translation: Empty,
+            rotation: Empty,
+            scaling: Empty,
+            pivot: Empty,
+            shader: Empty,
             phantom: PhantomData,
         }
     }
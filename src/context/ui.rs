@@ -0,0 +1,775 @@
+//! Immediate-mode user interface widgets drawn with the UI camera.
+
+use std::{ops::RangeInclusive, rc::Rc};
+
+use gilrs::Button;
+use glam::{Affine2, Vec2};
+use winit::keyboard::KeyCode;
+
+use super::{Context, ContextInner};
+use crate::ui::Direction;
+
+/// How the user interface widgets are drawn.
+///
+/// All sprites are drawn "nine-slice" style: the sprite is divided into a 3x3 grid of equally sized parts, the corners are drawn as-is and the edges and center are stretched to fit the widget.
+/// When a sprite is not set nothing is drawn for that part of the widget.
+///
+/// Set with [`UiContext::set_style`].
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::exhaustive_structs)]
+pub struct UiStyle {
+    /// Font asset used to draw all text.
+    pub font: String,
+    /// Sprite asset for the background of a button.
+    ///
+    /// Defaults to `None`.
+    pub button: Option<String>,
+    /// Sprite asset for the background of a button when the mouse is on top of it, falls back to [`Self::button`].
+    ///
+    /// Defaults to `None`.
+    pub button_hover: Option<String>,
+    /// Sprite asset for the background of a button when it's being pressed, falls back to [`Self::button_hover`].
+    ///
+    /// Defaults to `None`.
+    pub button_pressed: Option<String>,
+    /// Sprite asset for the box of an unchecked checkbox.
+    ///
+    /// Defaults to `None`.
+    pub checkbox: Option<String>,
+    /// Sprite asset for the box of a checked checkbox.
+    ///
+    /// When not set an `x` character is drawn inside the box.
+    ///
+    /// Defaults to `None`.
+    pub checkbox_checked: Option<String>,
+    /// Sprite asset for the track of a slider.
+    ///
+    /// Defaults to `None`.
+    pub slider: Option<String>,
+    /// Sprite asset for the handle of a slider.
+    ///
+    /// When not set a `|` character is drawn as the handle.
+    ///
+    /// Defaults to `None`.
+    pub slider_handle: Option<String>,
+    /// Sprite asset drawn over the background of the widget with the focus, use a sprite with a transparent center to frame the widget.
+    ///
+    /// When not set a `>` character is drawn left of the focused widget.
+    ///
+    /// Defaults to `None`.
+    pub focus: Option<String>,
+    /// Width in pixels of the track of a slider.
+    ///
+    /// Defaults to `64.0`.
+    pub slider_width: f32,
+    /// Pixels between the border of a widget and its text.
+    ///
+    /// Defaults to `2.0`.
+    pub padding: f32,
+    /// Pixels between widgets.
+    ///
+    /// Defaults to `2.0`.
+    pub spacing: f32,
+}
+
+impl UiStyle {
+    /// Create a style drawing all text with a font, without any sprites.
+    ///
+    /// # Arguments
+    ///
+    /// * `font` - Font asset used to draw all text.
+    #[inline]
+    #[must_use]
+    pub fn new(font: impl Into<String>) -> Self {
+        Self {
+            font: font.into(),
+            button: None,
+            button_hover: None,
+            button_pressed: None,
+            checkbox: None,
+            checkbox_checked: None,
+            slider: None,
+            slider_handle: None,
+            focus: None,
+            slider_width: 64.0,
+            padding: 2.0,
+            spacing: 2.0,
+        }
+    }
+
+    /// Set the sprites for the background of buttons.
+    #[inline]
+    #[must_use]
+    pub fn with_button_sprites(
+        mut self,
+        button: impl Into<String>,
+        hover: impl Into<String>,
+        pressed: impl Into<String>,
+    ) -> Self {
+        self.button = Some(button.into());
+        self.button_hover = Some(hover.into());
+        self.button_pressed = Some(pressed.into());
+
+        self
+    }
+
+    /// Set the sprites for the boxes of checkboxes.
+    #[inline]
+    #[must_use]
+    pub fn with_checkbox_sprites(
+        mut self,
+        unchecked: impl Into<String>,
+        checked: impl Into<String>,
+    ) -> Self {
+        self.checkbox = Some(unchecked.into());
+        self.checkbox_checked = Some(checked.into());
+
+        self
+    }
+
+    /// Set the sprites for the tracks and handles of sliders.
+    #[inline]
+    #[must_use]
+    pub fn with_slider_sprites(
+        mut self,
+        track: impl Into<String>,
+        handle: impl Into<String>,
+    ) -> Self {
+        self.slider = Some(track.into());
+        self.slider_handle = Some(handle.into());
+
+        self
+    }
+
+    /// Set the sprite drawn over the background of the widget with the focus.
+    #[inline]
+    #[must_use]
+    pub fn with_focus_sprite(mut self, focus: impl Into<String>) -> Self {
+        self.focus = Some(focus.into());
+
+        self
+    }
+
+    /// Set the width in pixels of the track of sliders.
+    #[inline]
+    #[must_use]
+    pub const fn with_slider_width(mut self, slider_width: f32) -> Self {
+        self.slider_width = slider_width;
+
+        self
+    }
+
+    /// Set the pixels between the border of a widget and its text.
+    #[inline]
+    #[must_use]
+    pub const fn with_padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+
+        self
+    }
+
+    /// Set the pixels between widgets.
+    #[inline]
+    #[must_use]
+    pub const fn with_spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+
+        self
+    }
+}
+
+/// Which keys and gamepad buttons navigate the user interface widgets without a mouse.
+///
+/// The focus is always moved with the arrow keys, the directional pad and the left stick of any connected gamepad.
+///
+/// Set with [`UiContext::set_navigation`].
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::exhaustive_structs)]
+pub struct UiNavigation {
+    /// Keys clicking the focused widget.
+    ///
+    /// Defaults to `[KeyCode::Enter, KeyCode::Space]`.
+    pub confirm_keys: Vec<KeyCode>,
+    /// Keys triggering the cancel action, see [`UiContext::cancelled`].
+    ///
+    /// Defaults to `[KeyCode::Escape, KeyCode::Backspace]`.
+    pub cancel_keys: Vec<KeyCode>,
+    /// Gamepad buttons clicking the focused widget.
+    ///
+    /// Defaults to `[GamepadButton::South]`.
+    pub confirm_buttons: Vec<Button>,
+    /// Gamepad buttons triggering the cancel action, see [`UiContext::cancelled`].
+    ///
+    /// Defaults to `[GamepadButton::East]`.
+    pub cancel_buttons: Vec<Button>,
+    /// How far the left stick must be pushed between `0.0` and `1.0` before it moves the focus.
+    ///
+    /// Defaults to `0.5`.
+    pub stick_threshold: f32,
+}
+
+impl Default for UiNavigation {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            confirm_keys: vec![KeyCode::Enter, KeyCode::Space],
+            cancel_keys: vec![KeyCode::Escape, KeyCode::Backspace],
+            confirm_buttons: vec![Button::South],
+            cancel_buttons: vec![Button::East],
+            stick_threshold: 0.5,
+        }
+    }
+}
+
+/// Draw immediate-mode user interface widgets.
+///
+/// Widgets must be created every frame in [`crate::Game::render`] and are placed below each other by default.
+/// They are drawn with the UI camera and respond to the left mouse button.
+/// Buttons, checkboxes and sliders can also receive the focus, which is moved to the closest widget in the direction pressed with the arrow keys or a gamepad, see [`UiNavigation`].
+/// Widgets are identified by the order in which they are created, so the same widgets should be created in the same order every frame.
+///
+/// Used by [`Context::ui`].
+pub struct UiContext<'ctx> {
+    /// Reference to the context the widgets will draw in.
+    pub(crate) ctx: &'ctx Context,
+}
+
+impl UiContext<'_> {
+    /// Set how the widgets are drawn.
+    ///
+    /// Must be called before any widgets are drawn, for example in [`crate::Game::init`].
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - Font and sprites to draw the widgets with.
+    #[inline]
+    pub fn set_style(&self, style: UiStyle) {
+        self.ctx.write(|ctx| {
+            ctx.ui.style = Some(Rc::new(style));
+        });
+    }
+
+    /// Set which keys and gamepad buttons navigate the widgets.
+    ///
+    /// # Arguments
+    ///
+    /// * `navigation` - Keys and buttons for the confirm and cancel actions.
+    #[inline]
+    pub fn set_navigation(&self, navigation: UiNavigation) {
+        self.ctx.write(|ctx| {
+            ctx.ui.navigation = navigation;
+        });
+    }
+
+    /// Get the widget with the focus.
+    ///
+    /// # Returns
+    ///
+    /// - Index of the focused widget in the order all widgets, including labels, are created every frame.
+    /// - `None` when no widget has the focus.
+    #[inline]
+    #[must_use]
+    pub fn focused(&self) -> Option<usize> {
+        self.ctx
+            .read(|ctx| ctx.ui.focused.map(|focused| focused as usize))
+    }
+
+    /// Move the focus to a widget.
+    ///
+    /// Useful for focusing the first button when opening a menu, otherwise the first widget is focused when a direction is pressed.
+    ///
+    /// # Arguments
+    ///
+    /// * `widget` - Index of the widget in the order all widgets, including labels, are created every frame, `None` removes the focus.
+    #[inline]
+    pub fn set_focus(&self, widget: Option<usize>) {
+        self.ctx.write(|ctx| {
+            ctx.ui.focused = widget.map(|widget| widget as u32);
+        });
+    }
+
+    /// Whether the cancel action is pressed this frame.
+    ///
+    /// Can be used to close a menu or go back to the previous one.
+    ///
+    /// # Returns
+    ///
+    /// - `true` when any of the keys or gamepad buttons set with [`Self::set_navigation`] is pressed.
+    #[inline]
+    #[must_use]
+    pub fn cancelled(&self) -> bool {
+        self.ctx.read(|ctx| ctx.ui.cancelled())
+    }
+
+    /// Move the position where the next widget will be placed.
+    ///
+    /// # Arguments
+    ///
+    /// * `(x, y)` - Tuple of the position on the UI camera.
+    #[inline]
+    pub fn set_position(&self, position: impl Into<(f32, f32)>) {
+        // Reduce compilation times
+        fn inner(this: &UiContext, (x, y): (f32, f32)) {
+            this.ctx.write(|ctx| {
+                ctx.ui.set_position(x, y);
+            });
+        }
+
+        inner(self, position.into());
+    }
+
+    /// Place all widgets created inside the function below each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `widgets` - Function creating the widgets.
+    #[inline]
+    pub fn vertical(&self, widgets: impl FnOnce(&Self)) {
+        self.layout(Direction::Vertical, widgets);
+    }
+
+    /// Place all widgets created inside the function next to each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `widgets` - Function creating the widgets.
+    #[inline]
+    pub fn horizontal(&self, widgets: impl FnOnce(&Self)) {
+        self.layout(Direction::Horizontal, widgets);
+    }
+
+    /// Draw a text label.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text to draw.
+    ///
+    /// # Panics
+    ///
+    /// - When no style is set with [`Self::set_style`].
+    /// - When asset failed loading.
+    #[inline]
+    pub fn label(&self, text: &str) {
+        self.ctx.write(|ctx| ctx.ui_label(text));
+    }
+
+    /// Draw a button.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text inside the button.
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the button is clicked this frame.
+    ///
+    /// # Panics
+    ///
+    /// - When no style is set with [`Self::set_style`].
+    /// - When asset failed loading.
+    #[inline]
+    #[must_use]
+    pub fn button(&self, text: &str) -> bool {
+        self.ctx.write(|ctx| ctx.ui_button(text))
+    }
+
+    /// Draw a checkbox with a label next to it, toggling the value when clicked.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text next to the checkbox.
+    /// * `checked` - Value to toggle.
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the value changed this frame.
+    ///
+    /// # Panics
+    ///
+    /// - When no style is set with [`Self::set_style`].
+    /// - When asset failed loading.
+    #[inline]
+    pub fn checkbox(&self, text: &str, checked: &mut bool) -> bool {
+        self.ctx.write(|ctx| ctx.ui_checkbox(text, checked))
+    }
+
+    /// Draw a horizontal slider with a label next to it, changing the value when dragged.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text next to the slider.
+    /// * `value` - Value to change, will be clamped to the range.
+    /// * `range` - Minimum and maximum value of the slider.
+    ///
+    /// # Returns
+    ///
+    /// - `true` when the value changed this frame.
+    ///
+    /// # Panics
+    ///
+    /// - When no style is set with [`Self::set_style`].
+    /// - When asset failed loading.
+    #[inline]
+    pub fn slider(&self, text: &str, value: &mut f32, range: RangeInclusive<f32>) -> bool {
+        self.ctx.write(|ctx| ctx.ui_slider(text, value, range))
+    }
+
+    /// Place all widgets created inside the function in a nested layout.
+    fn layout(&self, direction: Direction, widgets: impl FnOnce(&Self)) {
+        self.ctx.write(|ctx| ctx.ui.push_layout(direction));
+
+        widgets(self);
+
+        self.ctx.write(|ctx| {
+            let spacing = ctx.ui_style().spacing;
+            ctx.ui.pop_layout(spacing);
+        });
+    }
+}
+
+/// Widget implementations.
+impl ContextInner {
+    /// Draw a label.
+    fn ui_label(&mut self, text: &str) {
+        let style = self.ui_style();
+
+        // Reserve the space
+        let (text_width, text_height) = self.font(&style.font).text_size(text);
+        let (_id, x, y) = self.ui.allocate(
+            style.padding.mul_add(2.0, text_width),
+            style.padding.mul_add(2.0, text_height),
+            style.spacing,
+        );
+
+        self.ui_draw_text(&style.font, text, x + style.padding, y + style.padding);
+    }
+
+    /// Draw a button and return whether it's clicked.
+    fn ui_button(&mut self, text: &str) -> bool {
+        let style = self.ui_style();
+
+        // Reserve the space
+        let (text_width, text_height) = self.font(&style.font).text_size(text);
+        let width = style.padding.mul_add(2.0, text_width);
+        let height = style.padding.mul_add(2.0, text_height);
+        let (id, x, y) = self.ui.allocate(width, height, style.spacing);
+
+        // Handle the mouse and the focus
+        let interaction = self
+            .ui
+            .interact(id, (x, y, width, height), self.ui_mouse(), false);
+
+        // Draw the background based on the state, falling back to the less specific sprites
+        let sprite = if interaction.held {
+            style
+                .button_pressed
+                .as_ref()
+                .or(style.button_hover.as_ref())
+                .or(style.button.as_ref())
+        } else if interaction.hovered || interaction.focused {
+            style.button_hover.as_ref().or(style.button.as_ref())
+        } else {
+            style.button.as_ref()
+        };
+        if let Some(sprite) = sprite {
+            self.ui_draw_nine_slice(sprite, (x, y, width, height));
+        }
+
+        // Draw the focus over the background so it's not hidden by it
+        if interaction.focused {
+            self.ui_draw_focus(&style, (x, y, width, height));
+        }
+
+        self.ui_draw_text(&style.font, text, x + style.padding, y + style.padding);
+
+        interaction.clicked
+    }
+
+    /// Draw a checkbox and return whether it's toggled.
+    fn ui_checkbox(&mut self, text: &str, checked: &mut bool) -> bool {
+        let style = self.ui_style();
+
+        // Reserve the space, the box is a square with the height of the text
+        let (text_width, text_height) = self.font(&style.font).text_size(text);
+        let box_size = style.padding.mul_add(2.0, text_height);
+        let width = box_size + style.spacing + text_width;
+        let (id, x, y) = self.ui.allocate(width, box_size, style.spacing);
+
+        // Handle the mouse and the focus, clicking on the label also toggles it
+        let interaction = self
+            .ui
+            .interact(id, (x, y, width, box_size), self.ui_mouse(), false);
+        if interaction.clicked {
+            *checked = !*checked;
+        }
+
+        // Draw the box
+        let sprite = if *checked {
+            style.checkbox_checked.as_ref()
+        } else {
+            style.checkbox.as_ref()
+        };
+        if let Some(sprite) = sprite {
+            self.ui_draw_nine_slice(sprite, (x, y, box_size, box_size));
+        } else if *checked {
+            // Draw a character in the center of the box when there's no sprite
+            let (mark_width, mark_height) = self.font(&style.font).text_size("x");
+            self.ui_draw_text(
+                &style.font,
+                "x",
+                x + (box_size - mark_width) / 2.0,
+                y + (box_size - mark_height) / 2.0,
+            );
+        }
+
+        // Draw the focus over the box so it's not hidden by it
+        if interaction.focused {
+            self.ui_draw_focus(&style, (x, y, width, box_size));
+        }
+
+        self.ui_draw_text(
+            &style.font,
+            text,
+            x + box_size + style.spacing,
+            y + style.padding,
+        );
+
+        interaction.clicked
+    }
+
+    /// Draw a slider and return whether the value changed.
+    fn ui_slider(&mut self, text: &str, value: &mut f32, range: RangeInclusive<f32>) -> bool {
+        let style = self.ui_style();
+
+        // Reserve the space
+        let (text_width, text_height) = self.font(&style.font).text_size(text);
+        let (handle_width, _) = self.font(&style.font).text_size("|");
+        let handle_width = style.padding.mul_add(2.0, handle_width);
+        let track_width = style.slider_width.max(handle_width);
+        let height = style.padding.mul_add(2.0, text_height);
+        let width = track_width + style.spacing + text_width;
+        let (id, x, y) = self.ui.allocate(width, height, style.spacing);
+
+        // Handle the mouse and the focus, only the track can be dragged
+        let mouse = self.ui_mouse();
+        let interaction = self
+            .ui
+            .interact(id, (x, y, track_width, height), mouse, true);
+
+        // Move the value to the mouse position when dragging
+        let (min, max) = (*range.start(), *range.end());
+        let previous_value = *value;
+        if interaction.held {
+            if let Some((mouse_x, _)) = mouse {
+                let fraction = ((mouse_x - x - handle_width / 2.0) / (track_width - handle_width))
+                    .clamp(0.0, 1.0);
+                *value = crate::math::lerp(min, max, fraction);
+            }
+        }
+
+        // Move the value a tenth of the range when navigating horizontally
+        *value += self.ui.horizontal_step(id) * (max - min) / 10.0;
+        *value = value.clamp(min.min(max), max.max(min));
+
+        // Draw the track
+        if let Some(sprite) = &style.slider {
+            self.ui_draw_nine_slice(sprite, (x, y, track_width, height));
+        }
+
+        // Draw the handle at the position of the value
+        let fraction = if (max - min).abs() <= f32::EPSILON {
+            0.0
+        } else {
+            (*value - min) / (max - min)
+        };
+        let handle_x = fraction.mul_add(track_width - handle_width, x);
+        if let Some(sprite) = &style.slider_handle {
+            self.ui_draw_nine_slice(sprite, (handle_x, y, handle_width, height));
+        } else {
+            self.ui_draw_text(
+                &style.font,
+                "|",
+                handle_x + style.padding,
+                y + style.padding,
+            );
+        }
+
+        // Draw the focus over the track and the handle so it's not hidden by them
+        if interaction.focused {
+            self.ui_draw_focus(&style, (x, y, width, height));
+        }
+
+        self.ui_draw_text(
+            &style.font,
+            text,
+            x + track_width + style.spacing,
+            y + style.padding,
+        );
+
+        (*value - previous_value).abs() > f32::EPSILON
+    }
+
+    /// Draw the focus indicator for a widget.
+    fn ui_draw_focus(&mut self, style: &UiStyle, rectangle: (f32, f32, f32, f32)) {
+        if let Some(sprite) = &style.focus {
+            self.ui_draw_nine_slice(sprite, rectangle);
+        } else {
+            // Draw a character left of the widget when there's no sprite
+            let (x, y, _width, height) = rectangle;
+            let (mark_width, mark_height) = self.font(&style.font).text_size(">");
+            self.ui_draw_text(
+                &style.font,
+                ">",
+                x - mark_width - style.spacing,
+                y + (height - mark_height) / 2.0,
+            );
+        }
+    }
+
+    /// Get the style, panicking when it's not set.
+    pub(crate) fn ui_style(&self) -> Rc<UiStyle> {
+        Rc::clone(
+            self.ui
+                .style
+                .as_ref()
+                .expect("UI style not set, call `UiContext::set_style` first"),
+        )
+    }
+
+    /// Mouse position relative to the UI camera.
+    fn ui_mouse(&self) -> Option<(f32, f32)> {
+        self.input
+            .mouse()
+            .map(|(mouse_x, mouse_y)| self.camera(true).inverse_transform_point(mouse_x, mouse_y))
+    }
+
+    /// Draw text with the UI camera with the top left at the position.
+    fn ui_draw_text(&mut self, font: &str, text: &str, x: f32, y: f32) {
+        let font = self.font(font);
+        let camera = self.camera(true).affine_matrix();
+
+        for (glyph_x, glyph_y, sprite) in font.glyph_positions(text) {
+            self.graphics.push_instance(
+                None,
+                camera * Affine2::from_translation(Vec2::new(x + glyph_x, y + glyph_y)),
+                sprite.sub_rectangle,
+                sprite.texture,
+            );
+        }
+    }
+
+    /// Draw a sprite stretched to a rectangle with the UI camera, keeping the corners intact.
+    fn ui_draw_nine_slice(&mut self, sprite: &str, (x, y, width, height): (f32, f32, f32, f32)) {
+        let sprite = self.sprite(sprite);
+        let camera = self.camera(true).affine_matrix();
+
+        // Divide the sprite in three equal parts on each axis
+        let (source_x, source_y, source_width, source_height) = sprite.sub_rectangle;
+        let corner_width = (source_width / 3.0).floor().min(width / 2.0);
+        let corner_height = (source_height / 3.0).floor().min(height / 2.0);
+
+        // Source offset, source size, target offset and target size for each column and row
+        let columns = [
+            (0.0, corner_width, 0.0, corner_width),
+            (
+                corner_width,
+                corner_width.mul_add(-2.0, source_width),
+                corner_width,
+                corner_width.mul_add(-2.0, width),
+            ),
+            (
+                source_width - corner_width,
+                corner_width,
+                width - corner_width,
+                corner_width,
+            ),
+        ];
+        let rows = [
+            (0.0, corner_height, 0.0, corner_height),
+            (
+                corner_height,
+                corner_height.mul_add(-2.0, source_height),
+                corner_height,
+                corner_height.mul_add(-2.0, height),
+            ),
+            (
+                source_height - corner_height,
+                corner_height,
+                height - corner_height,
+                corner_height,
+            ),
+        ];
+
+        for (column_source, column_source_size, column_offset, column_size) in columns {
+            for (row_source, row_source_size, row_offset, row_size) in rows {
+                // Skip the parts that have no size
+                if column_source_size <= 0.0
+                    || row_source_size <= 0.0
+                    || column_size <= 0.0
+                    || row_size <= 0.0
+                {
+                    continue;
+                }
+
+                // Stretch the part to the target size
+                let affine_matrix = camera
+                    * Affine2::from_scale_angle_translation(
+                        Vec2::new(column_size / column_source_size, row_size / row_source_size),
+                        0.0,
+                        Vec2::new(x + column_offset, y + row_offset),
+                    );
+
+                self.graphics.push_instance(
+                    None,
+                    affine_matrix,
+                    (
+                        source_x + column_source,
+                        source_y + row_source,
+                        column_source_size,
+                        row_source_size,
+                    ),
+                    sprite.texture,
+                );
+            }
+        }
+    }
+}
+
+/// Immediate-mode user interface.
+impl Context {
+    /// Draw immediate-mode user interface widgets with the UI camera.
+    ///
+    /// A style must be set with [`UiContext::set_style`] before drawing any widgets.
+    ///
+    /// # Returns
+    ///
+    /// - A helper struct allowing you to draw widgets.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chuot::context::ui::UiStyle;
+    /// # fn call(ctx: chuot::Context, mut volume: f32, mut muted: bool) {
+    /// // Once in `Game::init`
+    /// ctx.ui().set_style(UiStyle::new("Beachball"));
+    ///
+    /// // Every frame in `Game::render`
+    /// ctx.ui().label("Settings");
+    /// ctx.ui().slider("Volume", &mut volume, 0.0..=1.0);
+    /// ctx.ui().checkbox("Mute", &mut muted);
+    /// ctx.ui().horizontal(|ui| {
+    ///     if ui.button("Play") {
+    ///         // Start the game
+    ///     }
+    ///     if ui.button("Quit") {
+    ///         ctx.exit();
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn ui(&self) -> UiContext<'_> {
+        UiContext { ctx: self }
+    }
+}
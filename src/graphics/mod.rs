@@ -10,11 +10,11 @@ mod uniform;
 use std::sync::Arc;
 
 use data::TexturedVertex;
-use glam::Affine2;
+use glam::{Affine2, Vec2};
 use hashbrown::HashMap;
 #[cfg(feature = "embed-assets")]
 use imgref::ImgVec;
-use pipeline::Pipeline;
+use pipeline::{Pipeline, ScissorRect};
 use rgb::RGBA8;
 use wgpu::util::DeviceExt as _;
 use winit::window::Window;
@@ -81,6 +81,14 @@ pub(crate) struct Graphics {
     pub(crate) background_color: wgpu::Color,
     /// Viewport color
     pub(crate) viewport_color: wgpu::Color,
+    /// Rectangle on the buffer instances are currently clipped to, `None` is the whole buffer.
+    pub(crate) scissor: Option<ScissorRect>,
+    /// Amount of instances pushed this frame that are visible.
+    pub(crate) drawn_instances: usize,
+    /// Amount of instances pushed this frame that are skipped because they are outside of the visible area.
+    pub(crate) culled_instances: usize,
+    /// Amount of `(drawn, culled)` instances of the previous frame.
+    pub(crate) previous_instance_counts: (usize, usize),
 }
 
 impl Graphics {
@@ -139,8 +147,9 @@ impl Graphics {
                     required_limits: wgpu::Limits::downlevel_webgl2_defaults()
                         .using_resolution(adapter.limits()),
                     memory_hints: wgpu::MemoryHints::Performance,
+                    experimental_features: wgpu::ExperimentalFeatures::disabled(),
+                    trace: wgpu::Trace::Off,
                 },
-                None,
             )
             .await
             .unwrap();
@@ -317,6 +326,10 @@ impl Graphics {
             letterbox,
             background_color,
             viewport_color,
+            scissor: None,
+            drawn_instances: 0,
+            culled_instances: 0,
+            previous_instance_counts: (0, 0),
         }
     }
 
@@ -342,6 +355,7 @@ impl Graphics {
             label: Some("Main Render Pass"),
             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
                 view: &self.downscale.texture_view,
+                depth_slice: None,
                 resolve_target: None,
                 ops: wgpu::Operations {
                     load: wgpu::LoadOp::Clear(self.background_color),
@@ -362,6 +376,7 @@ impl Graphics {
             &mut render_pass,
             &self.screen_info,
             &self.atlas,
+            (self.buffer_width as u32, self.buffer_height as u32),
         );
 
         // Loop over all instances from the main shader and all custom shaders
@@ -374,6 +389,7 @@ impl Graphics {
                 &mut render_pass,
                 &self.screen_info,
                 &self.atlas,
+                (self.buffer_width as u32, self.buffer_height as u32),
             );
         }
 
@@ -397,6 +413,11 @@ impl Graphics {
 
         // Show the surface texture in the window
         surface_texture.present();
+
+        // Reset the statistics for the next frame
+        self.previous_instance_counts = (self.drawn_instances, self.culled_instances);
+        self.drawn_instances = 0;
+        self.culled_instances = 0;
     }
 
     /// Resize the render surface.
@@ -486,7 +507,7 @@ impl Graphics {
         shader_source.push_str(include_str!("../../shaders/custom_shader_base.wgsl"));
 
         // Setup the pipeline
-        let pipeline = Pipeline::new(
+        let mut pipeline = Pipeline::new(
             &shader_source,
             None,
             &self.device,
@@ -494,6 +515,9 @@ impl Graphics {
             &self.atlas,
         );
 
+        // Clip the same as the other pipelines
+        pipeline.set_scissor(self.scissor);
+
         self.custom_pipelines.insert(id.clone(), pipeline);
     }
 
@@ -505,6 +529,14 @@ impl Graphics {
         sub_rectangle: (f32, f32, f32, f32),
         texture_ref: TextureRef,
     ) {
+        // Skip the instance when it's outside of the visible area
+        if !is_visible(self.visible_rectangle(), &transformation, &sub_rectangle) {
+            self.culled_instances += 1;
+
+            return;
+        }
+        self.drawn_instances += 1;
+
         match custom_shader {
             Some(path) => self
                 .custom_pipelines
@@ -517,12 +549,47 @@ impl Graphics {
         }
     }
 
+    /// Clip all instances pushed after this call to a rectangle on the buffer.
+    ///
+    /// `None` clips to the whole buffer again.
+    pub(crate) fn set_scissor(&mut self, scissor: Option<(f32, f32, f32, f32)>) {
+        // Convert to whole pixels inside of the buffer
+        self.scissor = scissor.map(|(x, y, width, height)| {
+            let x = x.clamp(0.0, self.buffer_width);
+            let y = y.clamp(0.0, self.buffer_height);
+            let width = width.clamp(0.0, self.buffer_width - x);
+            let height = height.clamp(0.0, self.buffer_height - y);
+
+            (x as u32, y as u32, width as u32, height as u32)
+        });
+
+        self.default_pipeline.set_scissor(self.scissor);
+        for pipeline in self.custom_pipelines.values_mut() {
+            pipeline.set_scissor(self.scissor);
+        }
+    }
+
     /// Extend the instances of the default shader or a custom shader.
     pub(crate) fn extend_instances(
         &mut self,
         custom_shader: Option<&str>,
         items: impl Iterator<Item = (Affine2, (f32, f32, f32, f32), TextureRef)>,
     ) {
+        // Skip the instances outside of the visible area
+        let visible_rectangle = self.visible_rectangle();
+        let mut drawn = 0;
+        let mut culled = 0;
+        let items = items.filter(|(transformation, sub_rectangle, _)| {
+            let visible = is_visible(visible_rectangle, transformation, sub_rectangle);
+            if visible {
+                drawn += 1;
+            } else {
+                culled += 1;
+            }
+
+            visible
+        });
+
         match custom_shader {
             Some(path) => self
                 .custom_pipelines
@@ -531,7 +598,40 @@ impl Graphics {
                 .extend_instances(items),
             None => self.default_pipeline.extend_instances(items),
         }
+
+        self.drawn_instances += drawn;
+        self.culled_instances += culled;
     }
+
+    /// Rectangle `(x, y, width, height)` of the buffer that's currently drawn to.
+    fn visible_rectangle(&self) -> (f32, f32, f32, f32) {
+        self.scissor.map_or(
+            (0.0, 0.0, self.buffer_width, self.buffer_height),
+            |(x, y, width, height)| (x as f32, y as f32, width as f32, height as f32),
+        )
+    }
+}
+
+/// Whether a transformed instance overlaps with the visible rectangle.
+///
+/// Uses the axis-aligned bounding box of the transformed quad so rotated and scaled instances are also handled.
+fn is_visible(
+    (visible_x, visible_y, visible_width, visible_height): (f32, f32, f32, f32),
+    transformation: &Affine2,
+    (_, _, width, height): &(f32, f32, f32, f32),
+) -> bool {
+    // Transform the sides of the quad
+    let x_axis = transformation.matrix2.x_axis * *width;
+    let y_axis = transformation.matrix2.y_axis * *height;
+
+    // Calculate the bounding box of all corners
+    let min = transformation.translation + x_axis.min(Vec2::ZERO) + y_axis.min(Vec2::ZERO);
+    let max = transformation.translation + x_axis.max(Vec2::ZERO) + y_axis.max(Vec2::ZERO);
+
+    min.x < visible_x + visible_width
+        && max.x > visible_x
+        && min.y < visible_y + visible_height
+        && max.y > visible_y
 }
 
 /// Convert an `u32` color to a WGPU [`wgpu::Color`] taking in account sRGB.
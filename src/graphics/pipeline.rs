@@ -10,6 +10,11 @@ use super::{
     data::TexturedVertex,
 };
 
+/// Rectangle `(x, y, width, height)` on the buffer in pixels to clip instances to.
+///
+/// Must be inside of the buffer, the vertical position is from the top of the buffer like all other positions.
+pub(crate) type ScissorRect = (u32, u32, u32, u32);
+
 /// The flow for rendering instances with a shader.
 pub(crate) struct Pipeline {
     /// All instances to render.
@@ -18,6 +23,10 @@ pub(crate) struct Pipeline {
     render: wgpu::RenderPipeline,
     /// GPU buffer reference to all instances of the texture squares.
     instance_buffer: wgpu::Buffer,
+    /// Clipping rectangles with the index of the first instance they apply to.
+    ///
+    /// `None` clips to the whole buffer.
+    scissors: Vec<(u32, Option<ScissorRect>)>,
 }
 
 impl Pipeline {
@@ -104,6 +113,7 @@ impl Pipeline {
             instances,
             render: render_pipeline,
             instance_buffer,
+            scissors: Vec::new(),
         }
     }
 
@@ -118,9 +128,12 @@ impl Pipeline {
         render_pass: &mut wgpu::RenderPass<'_>,
         screen_info: &UniformState<ScreenInfo>,
         atlas: &Atlas,
+        (buffer_width, buffer_height): (u32, u32),
     ) {
         if self.instances.is_empty() {
             // Nothing to render when there's no instances
+            self.scissors.clear();
+
             return;
         }
 
@@ -165,8 +178,28 @@ impl Pipeline {
         // Set the instances
         render_pass.set_vertex_buffer(1, self.instance_buffer.slice(..));
 
-        // Draw the instances
-        render_pass.draw_indexed(0..6, 0, 0..instances_len as u32);
+        // Draw the instances in ranges, each clipped to its own rectangle
+        let mut start = 0;
+        let mut scissor = None;
+        for (next_start, next_scissor) in self
+            .scissors
+            .drain(..)
+            .chain(std::iter::once((instances_len as u32, None)))
+        {
+            // Skip ranges without any instances
+            if next_start > start {
+                // The buffer is rendered upside down, so flip the rectangle vertically
+                let (x, y, width, height) = scissor.map_or(
+                    (0, 0, buffer_width, buffer_height),
+                    |(x, y, width, height)| (x, buffer_height - y - height, width, height),
+                );
+                render_pass.set_scissor_rect(x, y, width, height);
+                render_pass.draw_indexed(0..6, 0, start..next_start);
+            }
+
+            start = next_start;
+            scissor = next_scissor;
+        }
 
         // Upload the instance buffer
         if !instance_buffer_already_pushed {
@@ -188,6 +221,19 @@ impl Pipeline {
             .push(transformation, sub_rectangle, texture_ref);
     }
 
+    /// Clip all instances pushed after this call to a rectangle on the buffer.
+    pub(crate) fn set_scissor(&mut self, scissor: Option<ScissorRect>) {
+        let start = self.instances.len() as u32;
+
+        // Replace the previous rectangle when no instances have been pushed with it
+        match self.scissors.last_mut() {
+            Some((previous_start, previous_scissor)) if *previous_start == start => {
+                *previous_scissor = scissor;
+            }
+            _ => self.scissors.push((start, scissor)),
+        }
+    }
+
     /// Extend the instances of the default shader or a custom shader.
     pub(crate) fn extend_instances(
         &mut self,
@@ -157,6 +157,7 @@ impl PostProcessingState {
             label: Some("Post Processing Render Pass"),
             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
                 view,
+                depth_slice: None,
                 resolve_target: None,
                 ops: wgpu::Operations {
                     load: wgpu::LoadOp::Clear(background_color),
@@ -0,0 +1,113 @@
+//! State of connected gamepads, kept by the engine so it can be recorded and replayed.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use gilrs::{Axis, Button, Gamepad, Gilrs};
+use hashbrown::HashMap;
+use nanoserde::{DeBin, SerBin};
+
+use super::{AXES, BUTTONS, ButtonState};
+
+/// Identifier of a connected gamepad.
+///
+/// Stays the same when the gamepad is reconnected while the game is running.
+/// While replaying an [`super::InputRecording`] the gamepads are numbered in the order they were connected during the recording, starting at `0`.
+///
+/// Replaces [`gilrs::GamepadId`](https://docs.rs/gilrs/latest/gilrs/struct.GamepadId.html) in the gamepad methods, because the gamepads of a replayed recording are not known to `gilrs`.
+/// A `gilrs` ID can be converted with [`From`], and both IDs convert to the same number with [`usize::from`], so an ID can be compared with a `gilrs` gamepad with `usize::from(id) == usize::from(gilrs_id)`.
+/// Converting back to a `gilrs` ID is not possible because `gilrs` doesn't allow creating them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GamepadId(pub(crate) usize);
+
+impl From<gilrs::GamepadId> for GamepadId {
+    #[inline]
+    fn from(id: gilrs::GamepadId) -> Self {
+        Self(usize::from(id))
+    }
+}
+
+impl From<GamepadId> for usize {
+    #[inline]
+    fn from(id: GamepadId) -> Self {
+        id.0
+    }
+}
+
+impl Display for GamepadId {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Single change of the state of a gamepad.
+///
+/// Buttons and axes are stored as indices to keep the recording compact.
+#[derive(Debug, Clone, Copy, PartialEq, DeBin, SerBin)]
+pub enum GamepadEvent {
+    /// Gamepad is connected.
+    Connected,
+    /// Gamepad is disconnected.
+    Disconnected,
+    /// Button goes from "not pressed" to "pressed".
+    ButtonPressed(u16),
+    /// Button goes from "pressed" to "not pressed".
+    ButtonReleased(u16),
+    /// Button was already held when the recording started.
+    ButtonHeld(u16),
+    /// How far a button is pressed changed, for analog triggers.
+    ButtonChanged(u16, f32),
+    /// Position of an axis changed.
+    AxisChanged(u16, f32),
+}
+
+/// Buttons and axes of a connected gamepad.
+#[derive(Default)]
+pub(crate) struct GamepadState {
+    /// Timing of button presses and releases.
+    pub(crate) buttons: HashMap<Button, ButtonState>,
+    /// How far each button is pressed between `0.0` and `1.0`.
+    pub(crate) button_values: HashMap<Button, f32>,
+    /// Position of each axis between `-1.0` and `1.0`.
+    pub(crate) axes: HashMap<Axis, f32>,
+}
+
+impl GamepadState {
+    /// Take the current state of all connected gamepads.
+    pub(crate) fn all_connected(gilrs: &Gilrs, tick: u64) -> BTreeMap<GamepadId, Self> {
+        gilrs
+            .gamepads()
+            .map(|(id, gamepad)| (GamepadId::from(id), Self::from_gamepad(&gamepad, tick)))
+            .collect()
+    }
+
+    /// Take the current state of a gamepad, buttons already held don't trigger a press.
+    fn from_gamepad(gamepad: &Gamepad<'_>, tick: u64) -> Self {
+        let mut state = Self::default();
+
+        for &button in BUTTONS {
+            let Some(button_data) = gamepad.button_data(button) else {
+                continue;
+            };
+
+            if button_data.is_pressed() {
+                state
+                    .buttons
+                    .insert(button, ButtonState::already_held(tick));
+            }
+            state.button_values.insert(button, button_data.value());
+        }
+        for &axis in AXES {
+            if let Some(axis_data) = gamepad.axis_data(axis) {
+                state.axes.insert(axis, axis_data.value());
+            }
+        }
+
+        state
+    }
+
+    /// Handle the state changes using the update tick to respond to changes.
+    pub(crate) fn update(&mut self) {
+        self.buttons.values_mut().for_each(ButtonState::update);
+    }
+}
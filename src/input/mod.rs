@@ -0,0 +1,1716 @@
+//! Handle different input events.
+//!
+//! Physical inputs can be mapped to named actions and axes with [`InputBindings`].
+
+mod gamepad;
+mod recording;
+mod stick;
+mod touch;
+
+use std::{
+    collections::{BTreeMap, btree_map::Entry},
+    fmt::Display,
+    str::Chars,
+    sync::OnceLock,
+};
+
+use gilrs::{
+    Axis, Button, EventType, Gamepad, Gilrs, GilrsBuilder,
+    ff::{
+        BaseEffect, BaseEffectType, Effect, EffectBuilder, Repeat, Replay as EffectReplay, Ticks,
+    },
+};
+use hashbrown::HashMap;
+use nanoserde::{DeRon, DeRonErr, DeRonState, SerRon, SerRonState};
+use smallvec::SmallVec;
+use winit::{
+    event::{ElementState, Ime, MouseButton, MouseScrollDelta, TouchPhase, WindowEvent},
+    keyboard::{KeyCode, PhysicalKey},
+};
+
+pub use self::{
+    gamepad::GamepadId,
+    recording::InputRecording,
+    stick::{DeadzoneShape, ResponseCurve, Stick, StickSettings},
+};
+use self::{
+    gamepad::{GamepadEvent, GamepadState},
+    recording::{InputEvent, Recorder, Replay},
+    touch::Touch,
+};
+use crate::{
+    assets::{Id, loadable::Loadable, loader::ron::RonLoader},
+    context::ContextInner,
+    graphics::Graphics,
+};
+
+/// How far an analog binding must be engaged before an action is held, or a gamepad axis is captured.
+const ACTION_THRESHOLD: f32 = 0.5;
+
+/// Physical input that can trigger an action or move an axis.
+///
+/// Serialized in RON as a string, for example `"Key(Space)"`, `"Mouse(Left)"`, `"GamepadButton(South)"`, `"GamepadAxis(+LeftStickX)"` or `"GamepadAxis(-LeftStickY)"`.
+/// Mouse buttons without a name are serialized as a number, for example `"Mouse(4)"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Binding {
+    /// Keyboard key, uses physical keys in the US layout.
+    Key(KeyCode),
+    /// Mouse button.
+    Mouse(MouseButton),
+    /// Button on any connected gamepad.
+    GamepadButton(Button),
+    /// Gamepad axis on any connected gamepad being moved in the positive direction, for example right or up.
+    GamepadAxisPositive(Axis),
+    /// Gamepad axis on any connected gamepad being moved in the negative direction, for example left or down.
+    GamepadAxisNegative(Axis),
+}
+
+impl Binding {
+    /// Parse a binding from the string representation.
+    fn parse(string: &str) -> Option<Self> {
+        // Split the variant name from the value inside the parentheses
+        let (variant, value) = string.trim().strip_suffix(')')?.split_once('(')?;
+
+        match variant {
+            "Key" => key_from_name(value).map(Self::Key),
+            "Mouse" => match value {
+                "Left" => Some(MouseButton::Left),
+                "Right" => Some(MouseButton::Right),
+                "Middle" => Some(MouseButton::Middle),
+                "Back" => Some(MouseButton::Back),
+                "Forward" => Some(MouseButton::Forward),
+                other => other.parse().ok().map(MouseButton::Other),
+            }
+            .map(Self::Mouse),
+            "GamepadButton" => button_from_name(value).map(Self::GamepadButton),
+            "GamepadAxis" => {
+                if let Some(axis) = value.strip_prefix('+') {
+                    axis_from_name(axis).map(Self::GamepadAxisPositive)
+                } else {
+                    axis_from_name(value.strip_prefix('-')?).map(Self::GamepadAxisNegative)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for Binding {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Key(key) => write!(f, "Key({})", key_name(*key)),
+            Self::Mouse(MouseButton::Left) => write!(f, "Mouse(Left)"),
+            Self::Mouse(MouseButton::Right) => write!(f, "Mouse(Right)"),
+            Self::Mouse(MouseButton::Middle) => write!(f, "Mouse(Middle)"),
+            Self::Mouse(MouseButton::Back) => write!(f, "Mouse(Back)"),
+            Self::Mouse(MouseButton::Forward) => write!(f, "Mouse(Forward)"),
+            Self::Mouse(MouseButton::Other(button)) => write!(f, "Mouse({button})"),
+            Self::GamepadButton(button) => write!(f, "GamepadButton({})", button_name(*button)),
+            Self::GamepadAxisPositive(axis) => write!(f, "GamepadAxis(+{})", axis_name(*axis)),
+            Self::GamepadAxisNegative(axis) => write!(f, "GamepadAxis(-{})", axis_name(*axis)),
+        }
+    }
+}
+
+impl SerRon for Binding {
+    #[inline]
+    fn ser_ron(&self, indent_level: usize, state: &mut SerRonState) {
+        self.to_string().ser_ron(indent_level, state);
+    }
+}
+
+impl DeRon for Binding {
+    #[inline]
+    fn de_ron(state: &mut DeRonState, input: &mut Chars) -> Result<Self, DeRonErr> {
+        let string = String::de_ron(state, input)?;
+
+        Self::parse(&string).ok_or_else(|| state.err_parse("binding"))
+    }
+}
+
+/// Bindings moving a named axis between `-1.0` and `1.0`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, DeRon, SerRon)]
+#[allow(clippy::exhaustive_structs)]
+pub struct AxisBinding {
+    /// Bindings moving the axis towards `-1.0`.
+    #[nserde(default)]
+    pub negative: Vec<Binding>,
+    /// Bindings moving the axis towards `1.0`.
+    #[nserde(default)]
+    pub positive: Vec<Binding>,
+}
+
+/// Map physical inputs to named actions and axes.
+///
+/// Can be loaded as a RON asset with [`crate::Context::load_input_bindings`], which will be hot-reloaded when changed:
+///
+/// ```ron
+/// (
+///     actions: {
+///         "jump": ["Key(Space)", "GamepadButton(South)"],
+///     },
+///     axes: {
+///         "move_x": (
+///             negative: ["Key(ArrowLeft)", "GamepadAxis(-LeftStickX)"],
+///             positive: ["Key(ArrowRight)", "GamepadAxis(+LeftStickX)"],
+///         ),
+///     },
+/// )
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, DeRon, SerRon)]
+#[allow(clippy::exhaustive_structs)]
+pub struct InputBindings {
+    /// Actions that are either held or not, with all bindings triggering it.
+    #[nserde(default)]
+    pub actions: BTreeMap<String, Vec<Binding>>,
+    /// Axes with a value between `-1.0` and `1.0`.
+    #[nserde(default)]
+    pub axes: BTreeMap<String, AxisBinding>,
+}
+
+impl InputBindings {
+    /// Add a binding to an action, creating the action if it doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn with_action(mut self, action: impl Into<String>, binding: Binding) -> Self {
+        self.actions.entry(action.into()).or_default().push(binding);
+
+        self
+    }
+
+    /// Add a pair of bindings to an axis, creating the axis if it doesn't exist.
+    #[inline]
+    #[must_use]
+    pub fn with_axis(
+        mut self,
+        axis: impl Into<String>,
+        negative: Binding,
+        positive: Binding,
+    ) -> Self {
+        let axis = self.axes.entry(axis.into()).or_default();
+        axis.negative.push(negative);
+        axis.positive.push(positive);
+
+        self
+    }
+
+    /// Serialize to a RON string, so it can be saved and loaded again as an asset.
+    #[inline]
+    #[must_use]
+    pub fn to_ron(&self) -> String {
+        self.serialize_ron()
+    }
+}
+
+impl Loadable for InputBindings {
+    #[inline]
+    fn load_if_exists(id: &Id, ctx: &mut ContextInner) -> Option<Self> {
+        ctx.asset_source.load_if_exists::<RonLoader, Self>(id)
+    }
+}
+
+/// Any button state.
+#[derive(Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub(crate) struct ButtonState {
+    /// Whether the button is being held down this update tick.
+    is_down: bool,
+    /// Whether the button received a pressed event this update tick.
+    is_pressed: bool,
+    /// Whether the button received a released event this update tick.
+    is_released: bool,
+    /// Whether the button received a pressed event from the OS key repeat this update tick.
+    is_repeated: bool,
+    /// Update tick of the last pressed event.
+    pressed_tick: Option<u64>,
+    /// Update tick of the last released event.
+    released_tick: Option<u64>,
+}
+
+impl ButtonState {
+    /// Create a new state for a button that's already held, without triggering a press.
+    pub(crate) const fn already_held(tick: u64) -> Self {
+        Self {
+            is_down: true,
+            is_pressed: false,
+            is_released: false,
+            is_repeated: false,
+            pressed_tick: Some(tick),
+            released_tick: None,
+        }
+    }
+
+    /// Handle the state if the button is currently pressed.
+    pub(crate) const fn handle_event(&mut self, pressed: bool, repeat: bool, tick: u64) {
+        self.is_down = pressed;
+
+        // Repeated events are not a new press
+        if repeat {
+            self.is_repeated = true;
+        } else if pressed {
+            self.is_pressed = true;
+            self.pressed_tick = Some(tick);
+        } else {
+            self.is_released = true;
+            self.released_tick = Some(tick);
+        }
+    }
+
+    /// Handle the state changes using the update tick to respond to changes.
+    pub(crate) const fn update(&mut self) {
+        self.is_pressed = false;
+        self.is_released = false;
+        self.is_repeated = false;
+    }
+
+    /// Whether the button is being pressed now.
+    pub(crate) const fn held(&self) -> bool {
+        self.is_down
+    }
+
+    /// Whether the button goes from released to pressed.
+    pub(crate) const fn pressed(&self) -> bool {
+        self.is_pressed
+    }
+
+    /// Whether the button goes from pressed to released.
+    pub(crate) const fn released(&self) -> bool {
+        self.is_released
+    }
+
+    /// Whether the button goes from released to pressed or is repeated by the OS.
+    pub(crate) const fn pressed_or_repeated(&self) -> bool {
+        self.is_pressed || self.is_repeated
+    }
+
+    /// How many update ticks the button is held, including the current one.
+    pub(crate) fn held_duration(&self, tick: u64) -> u32 {
+        self.pressed_tick
+            .filter(|_| self.is_down)
+            .map_or(0, |pressed_tick| {
+                u32::try_from(tick - pressed_tick + 1).unwrap_or(u32::MAX)
+            })
+    }
+
+    /// Whether the button is pressed in the last amount of update ticks, including the current one.
+    pub(crate) fn pressed_within(&self, tick: u64, ticks: u32) -> bool {
+        self.pressed_tick
+            .is_some_and(|pressed_tick| tick - pressed_tick < u64::from(ticks))
+    }
+
+    /// Whether the button is released in the last amount of update ticks, including the current one.
+    pub(crate) fn released_within(&self, tick: u64, ticks: u32) -> bool {
+        self.released_tick
+            .is_some_and(|released_tick| tick - released_tick < u64::from(ticks))
+    }
+}
+
+/// Manager for handling different input events.
+pub(crate) struct Input {
+    /// Amount of update ticks since the start, for timing button presses.
+    tick: u64,
+    /// Mouse position.
+    ///
+    /// `None` if not on screen.
+    mouse: Option<(f32, f32)>,
+    /// Mouse button states.
+    mouse_buttons: HashMap<MouseButton, ButtonState>,
+    /// All keyboard buttons.
+    keys: HashMap<KeyCode, ButtonState>,
+    /// How much the mouse moved on the device this update tick.
+    mouse_motion: (f32, f32),
+    /// Horizontal scroll delta.
+    scroll_delta_x: f32,
+    /// Vertical scroll delta.
+    scroll_delta_y: f32,
+    /// Characters typed this update tick.
+    typed_text: String,
+    /// Fingers touching the screen, including the ones lifted this update tick.
+    touches: SmallVec<[Touch; 4]>,
+    /// Whether the first finger touching the screen acts as the left mouse button.
+    touch_emulates_mouse: bool,
+    /// Finger acting as the left mouse button.
+    mouse_touch: Option<u64>,
+    /// Gamepad input.
+    gilrs: Gilrs,
+    /// Buttons and axes of the connected gamepads, ordered by ID.
+    gamepads: BTreeMap<GamepadId, GamepadState>,
+    /// Gamepads connected this update tick.
+    gamepads_connected: SmallVec<[GamepadId; 4]>,
+    /// Gamepads disconnected this update tick.
+    gamepads_disconnected: SmallVec<[GamepadId; 4]>,
+    /// Force feedback effect playing for each gamepad, stops when dropped.
+    rumbles: HashMap<GamepadId, Effect>,
+    /// Deadzones and response curve of the sticks of all gamepads.
+    stick_settings: StickSettings,
+    /// Deadzones and response curve of the sticks overwritten for a single gamepad.
+    gamepad_stick_settings: HashMap<GamepadId, StickSettings>,
+    /// Mapping of physical inputs to actions and axes.
+    bindings: InputBindings,
+    /// Asset the bindings are loaded from, reloaded when it changes.
+    pub(crate) bindings_asset: Option<Id>,
+    /// Whether each action was held during the previous update tick.
+    actions_held: HashMap<String, bool>,
+    /// Whether the next pressed input is captured instead of triggering actions.
+    capturing: bool,
+    /// Input pressed while capturing.
+    captured: Option<Binding>,
+    /// Recording of all input events.
+    recording: Option<Recorder>,
+    /// Recording being replayed, ignoring all keyboard, mouse and gamepad events.
+    replay: Option<Replay>,
+}
+
+impl Input {
+    /// Setup the input.
+    pub(crate) fn new() -> Self {
+        let tick = 0;
+        let mouse = None;
+        let mouse_buttons = HashMap::new();
+        let keys = HashMap::new();
+        let mouse_motion = (0.0, 0.0);
+        let scroll_delta_x = 0.0;
+        let scroll_delta_y = 0.0;
+        let typed_text = String::new();
+
+        // Touches are not emulated as mouse input by default
+        let touches = SmallVec::new();
+        let touch_emulates_mouse = false;
+        let mouse_touch = None;
+
+        let gilrs = GilrsBuilder::new()
+            // Manually handle all updates
+            .set_update_state(false)
+            .build()
+            .unwrap();
+        // Gamepads connected before the game started don't always send a connected event
+        let gamepads = GamepadState::all_connected(&gilrs, tick);
+        let gamepads_connected = gamepads.keys().copied().collect();
+        let gamepads_disconnected = SmallVec::new();
+        let rumbles = HashMap::new();
+        let stick_settings = StickSettings::default();
+        let gamepad_stick_settings = HashMap::new();
+
+        // No actions are bound yet
+        let bindings = InputBindings::default();
+        let bindings_asset = None;
+        let actions_held = HashMap::new();
+        let capturing = false;
+        let captured = None;
+
+        // Nothing is being recorded or replayed
+        let recording = None;
+        let replay = None;
+
+        Self {
+            tick,
+            mouse,
+            mouse_buttons,
+            keys,
+            mouse_motion,
+            scroll_delta_x,
+            scroll_delta_y,
+            typed_text,
+            touches,
+            touch_emulates_mouse,
+            mouse_touch,
+            gilrs,
+            gamepads,
+            gamepads_connected,
+            gamepads_disconnected,
+            rumbles,
+            stick_settings,
+            gamepad_stick_settings,
+            bindings,
+            bindings_asset,
+            actions_held,
+            capturing,
+            captured,
+            recording,
+            replay,
+        }
+    }
+
+    /// Handle a winit window event.
+    #[inline]
+    pub(crate) fn handle_event(&mut self, event: WindowEvent, graphics: &Graphics) {
+        // Ignore the real input when replaying a recording
+        if self.replay.is_some() {
+            return;
+        }
+
+        // NOTE: Any new events added here must be added to `src/lib.rs` as well
+        match event {
+            // Handle keyboard buttons
+            WindowEvent::KeyboardInput { event, .. } => {
+                if let PhysicalKey::Code(keycode) = event.physical_key {
+                    self.apply_key(keycode, event.state == ElementState::Pressed, event.repeat);
+                }
+
+                // Store the typed text using the keyboard layout
+                if let Some(text) = event.text {
+                    if event.state == ElementState::Pressed {
+                        self.apply_text(&text);
+                    }
+                }
+            }
+            // Handle text typed with an input method editor
+            WindowEvent::Ime(Ime::Commit(text)) => self.apply_text(&text),
+            // Handle mouse cursor position
+            WindowEvent::CursorMoved { position, .. } => {
+                // Map the coordinates to the buffer
+                self.apply(InputEvent::MouseMoved(
+                    graphics.map_window_coordinate(position.x as f32, position.y as f32),
+                ));
+            }
+            // Handle mouse scroll wheel
+            WindowEvent::MouseWheel { delta, .. } => {
+                let (x, y) = match delta {
+                    // Treat a line as a single pixel
+                    MouseScrollDelta::LineDelta(x, y) => (x, y),
+                    MouseScrollDelta::PixelDelta(position) => {
+                        (position.x as f32, position.y as f32)
+                    }
+                };
+
+                self.apply(InputEvent::Scrolled(x, y));
+            }
+            // Handle mouse buttons
+            WindowEvent::MouseInput { state, button, .. } => {
+                let button = mouse_button_index(button);
+
+                self.apply(match state {
+                    ElementState::Pressed => InputEvent::MousePressed(button),
+                    ElementState::Released => InputEvent::MouseReleased(button),
+                });
+            }
+            // Handle fingers touching the screen
+            WindowEvent::Touch(touch) => {
+                // Map the coordinates to the buffer
+                let position = graphics
+                    .map_window_coordinate(touch.location.x as f32, touch.location.y as f32);
+                let is_mouse = self.mouse_touch == Some(touch.id);
+
+                match touch.phase {
+                    TouchPhase::Started => {
+                        self.apply(InputEvent::TouchStarted(touch.id, position));
+
+                        // The first finger acts as the left mouse button
+                        if self.touch_emulates_mouse && self.mouse_touch.is_none() {
+                            self.mouse_touch = Some(touch.id);
+                            self.apply(InputEvent::MouseMoved(position));
+                            self.apply(InputEvent::MousePressed(mouse_button_index(
+                                MouseButton::Left,
+                            )));
+                        }
+                    }
+                    TouchPhase::Moved => {
+                        self.apply(InputEvent::TouchMoved(touch.id, position));
+
+                        if is_mouse {
+                            self.apply(InputEvent::MouseMoved(position));
+                        }
+                    }
+                    TouchPhase::Ended | TouchPhase::Cancelled => {
+                        self.apply(InputEvent::TouchEnded(touch.id));
+
+                        if is_mouse {
+                            self.mouse_touch = None;
+                            self.apply(InputEvent::MouseReleased(mouse_button_index(
+                                MouseButton::Left,
+                            )));
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Handle raw mouse movement from a winit device event.
+    #[inline]
+    pub(crate) fn handle_mouse_motion(&mut self, (x, y): (f64, f64)) {
+        // Ignore the real input when replaying a recording
+        if self.replay.is_some() {
+            return;
+        }
+
+        self.apply(InputEvent::MouseMotion(x as f32, y as f32));
+    }
+
+    /// Change the state of a key, recording the change when recording.
+    fn apply_key(&mut self, keycode: KeyCode, is_down: bool, repeat: bool) {
+        if is_down && !repeat {
+            self.capture(Binding::Key(keycode));
+        }
+
+        // Register the key if it's not found and update the state
+        self.keys
+            .entry(keycode)
+            .or_default()
+            .handle_event(is_down, repeat, self.tick);
+
+        // Store the change with the current tick, keys without an index can't be recorded
+        if let Some((recording, key)) = self.recording.as_mut().zip(key_index(keycode)) {
+            recording.push(match (is_down, repeat) {
+                (true, false) => InputEvent::KeyPressed(key),
+                (true, true) => InputEvent::KeyRepeated(key),
+                (false, _) => InputEvent::KeyReleased(key),
+            });
+        }
+    }
+
+    /// Change the input state, recording the change when recording.
+    fn apply(&mut self, event: InputEvent) {
+        match &event {
+            InputEvent::KeyPressed(key)
+            | InputEvent::KeyRepeated(key)
+            | InputEvent::KeyReleased(key) => {
+                if let Some(keycode) = KEYS.get(usize::from(*key)).copied() {
+                    // Also records the change
+                    self.apply_key(
+                        keycode,
+                        !matches!(event, InputEvent::KeyReleased(_)),
+                        matches!(event, InputEvent::KeyRepeated(_)),
+                    );
+                }
+
+                return;
+            }
+            InputEvent::Gamepad(slot, gamepad_event) => {
+                // Also records the change
+                self.apply_gamepad(GamepadId(usize::from(*slot)), *gamepad_event);
+
+                return;
+            }
+            InputEvent::KeyHeld(key) => {
+                if let Some(keycode) = KEYS.get(usize::from(*key)).copied() {
+                    // Register without triggering a press
+                    self.keys
+                        .insert(keycode, ButtonState::already_held(self.tick));
+                }
+            }
+            InputEvent::MousePressed(button) | InputEvent::MouseReleased(button) => {
+                let button = mouse_button_from_index(*button);
+                let is_down = matches!(event, InputEvent::MousePressed(_));
+
+                if is_down {
+                    self.capture(Binding::Mouse(button));
+                }
+
+                // Register the button if it's not found and update the state
+                self.mouse_buttons
+                    .entry(button)
+                    .or_default()
+                    .handle_event(is_down, false, self.tick);
+            }
+            InputEvent::MouseHeld(button) => {
+                // Register without triggering a press
+                self.mouse_buttons.insert(
+                    mouse_button_from_index(*button),
+                    ButtonState::already_held(self.tick),
+                );
+            }
+            InputEvent::MouseMoved(position) => self.mouse = *position,
+            InputEvent::Scrolled(x, y) => {
+                self.scroll_delta_x = *x;
+                self.scroll_delta_y = *y;
+            }
+            InputEvent::Text(text) => self.typed_text.push_str(text),
+            InputEvent::TouchStarted(id, position) => {
+                // Replace a touch with the same ID that ended this update tick
+                self.touches.retain(|touch| touch.id != *id);
+                self.touches.push(Touch::new(*id, *position));
+            }
+            InputEvent::TouchMoved(id, position) => {
+                if let Some(touch) = self.touch(*id) {
+                    touch.position = *position;
+                }
+            }
+            InputEvent::MouseMotion(x, y) => {
+                // Multiple events can happen in a single update tick
+                self.mouse_motion.0 += x;
+                self.mouse_motion.1 += y;
+            }
+            InputEvent::TouchEnded(id) => {
+                if let Some(touch) = self.touch(*id) {
+                    touch.release();
+                }
+            }
+        }
+
+        // Store the change with the current tick
+        if let Some(recording) = &mut self.recording {
+            recording.push(event);
+        }
+    }
+
+    /// Change the state of a gamepad, recording the change when recording.
+    fn apply_gamepad(&mut self, gamepad_id: GamepadId, event: GamepadEvent) {
+        match event {
+            GamepadEvent::Connected => {
+                // Gamepads connected before the game started might already be known
+                if let Entry::Vacant(entry) = self.gamepads.entry(gamepad_id) {
+                    entry.insert(GamepadState::default());
+                    self.gamepads_connected.push(gamepad_id);
+                }
+            }
+            GamepadEvent::Disconnected => {
+                self.gamepads.remove(&gamepad_id);
+                self.gamepads_disconnected.push(gamepad_id);
+
+                // Stop rumbling
+                self.rumbles.remove(&gamepad_id);
+            }
+            GamepadEvent::ButtonPressed(button) | GamepadEvent::ButtonReleased(button) => {
+                let Some(button) = BUTTONS.get(usize::from(button)).copied() else {
+                    return;
+                };
+                let is_down = matches!(event, GamepadEvent::ButtonPressed(_));
+
+                if is_down {
+                    self.capture(Binding::GamepadButton(button));
+                }
+
+                // Register the button if it's not found and update the state
+                if let Some(gamepad) = self.gamepads.get_mut(&gamepad_id) {
+                    gamepad
+                        .buttons
+                        .entry(button)
+                        .or_default()
+                        .handle_event(is_down, false, self.tick);
+                }
+            }
+            GamepadEvent::ButtonHeld(button) => {
+                let Some(button) = BUTTONS.get(usize::from(button)).copied() else {
+                    return;
+                };
+
+                // Register without triggering a press
+                if let Some(gamepad) = self.gamepads.get_mut(&gamepad_id) {
+                    gamepad
+                        .buttons
+                        .insert(button, ButtonState::already_held(self.tick));
+                }
+            }
+            GamepadEvent::ButtonChanged(button, value) => {
+                let Some(button) = BUTTONS.get(usize::from(button)).copied() else {
+                    return;
+                };
+
+                if let Some(gamepad) = self.gamepads.get_mut(&gamepad_id) {
+                    gamepad.button_values.insert(button, value);
+                }
+            }
+            GamepadEvent::AxisChanged(axis, value) => {
+                let Some(axis) = AXES.get(usize::from(axis)).copied() else {
+                    return;
+                };
+
+                if value > ACTION_THRESHOLD {
+                    self.capture(Binding::GamepadAxisPositive(axis));
+                } else if value < -ACTION_THRESHOLD {
+                    self.capture(Binding::GamepadAxisNegative(axis));
+                }
+
+                if let Some(gamepad) = self.gamepads.get_mut(&gamepad_id) {
+                    gamepad.axes.insert(axis, value);
+                }
+            }
+        }
+
+        // Store the change with the current tick
+        if let Some(recording) = &mut self.recording {
+            recording.push_gamepad(gamepad_id, event);
+        }
+    }
+
+    /// Change the typed text, ignoring control characters such as backspace and enter.
+    fn apply_text(&mut self, text: &str) {
+        let text = text
+            .chars()
+            .filter(|character| !character.is_control())
+            .collect::<String>();
+
+        if !text.is_empty() {
+            self.apply(InputEvent::Text(text));
+        }
+    }
+
+    /// Update all registered buttons.
+    ///
+    /// Only allowed to be called once per update tick.
+    #[inline]
+    pub(crate) fn update(&mut self) {
+        // Remember which actions were held this tick, before the gamepad state changes, to detect pressing and releasing them
+        let mut actions_held = std::mem::take(&mut self.actions_held);
+        for (action, held) in &mut actions_held {
+            *held = self.action_held(action);
+        }
+        self.actions_held = actions_held;
+
+        // Update all button states, needed to handle "pressed" and "released"
+        self.mouse_buttons
+            .iter_mut()
+            .for_each(|(_, state)| state.update());
+        self.keys.iter_mut().for_each(|(_, state)| state.update());
+        self.gamepads.values_mut().for_each(GamepadState::update);
+
+        // Move to the next update tick for timing button presses
+        self.tick += 1;
+
+        // Typed text and mouse motion are only kept for a single update tick
+        self.typed_text.clear();
+        self.mouse_motion = (0.0, 0.0);
+
+        // Lifted fingers are only kept for a single update tick
+        self.touches.retain(|touch| touch.is_down);
+        self.touches.iter_mut().for_each(Touch::update);
+
+        // Connections are only kept for a single update tick
+        self.gamepads_connected.clear();
+        self.gamepads_disconnected.clear();
+
+        // Move the recording to the next tick
+        if let Some(recorder) = &mut self.recording {
+            recorder.recording.next_tick();
+        }
+
+        // Apply the recorded events of the next tick
+        self.replay_tick();
+
+        // Handle the gamepad events
+        while let Some(gamepad_event) = self.gilrs.next_event() {
+            self.gilrs.update(&gamepad_event);
+
+            // Ignore the real gamepads when replaying a recording
+            if self.replay.is_some() {
+                continue;
+            }
+
+            let event = match gamepad_event.event {
+                EventType::Connected => Some(GamepadEvent::Connected),
+                EventType::Disconnected => Some(GamepadEvent::Disconnected),
+                EventType::ButtonPressed(button, _) => {
+                    button_index(button).map(GamepadEvent::ButtonPressed)
+                }
+                EventType::ButtonReleased(button, _) => {
+                    button_index(button).map(GamepadEvent::ButtonReleased)
+                }
+                EventType::ButtonChanged(button, value, _) => {
+                    button_index(button).map(|button| GamepadEvent::ButtonChanged(button, value))
+                }
+                EventType::AxisChanged(axis, value, _) => {
+                    axis_index(axis).map(|axis| GamepadEvent::AxisChanged(axis, value))
+                }
+                _ => None,
+            };
+            if let Some(event) = event {
+                self.apply_gamepad(GamepadId::from(gamepad_event.id), event);
+            }
+        }
+    }
+
+    /// Check the mouse pressed state for a mouse button.
+    #[inline]
+    #[must_use]
+    pub(crate) fn mouse_pressed(&self, mouse_button: MouseButton) -> bool {
+        let Some(mouse_button_state) = self.mouse_buttons.get(&mouse_button) else {
+            return false;
+        };
+
+        mouse_button_state.pressed()
+    }
+
+    /// Check the mouse released state for a mouse button.
+    #[inline]
+    #[must_use]
+    pub(crate) fn mouse_released(&self, mouse_button: MouseButton) -> bool {
+        let Some(mouse_button_state) = self.mouse_buttons.get(&mouse_button) else {
+            return false;
+        };
+
+        mouse_button_state.released()
+    }
+
+    /// Check the mouse held state for a mouse button.
+    #[inline]
+    #[must_use]
+    pub(crate) fn mouse_held(&self, mouse_button: MouseButton) -> bool {
+        let Some(mouse_button_state) = self.mouse_buttons.get(&mouse_button) else {
+            return false;
+        };
+
+        mouse_button_state.held()
+    }
+
+    /// How many update ticks a mouse button is held, including the current one.
+    #[inline]
+    #[must_use]
+    pub(crate) fn mouse_held_duration(&self, mouse_button: MouseButton) -> u32 {
+        self.mouse_buttons
+            .get(&mouse_button)
+            .map_or(0, |state| state.held_duration(self.tick))
+    }
+
+    /// Whether a mouse button is pressed in the last amount of update ticks.
+    #[inline]
+    #[must_use]
+    pub(crate) fn mouse_pressed_within(&self, mouse_button: MouseButton, ticks: u32) -> bool {
+        self.mouse_buttons
+            .get(&mouse_button)
+            .is_some_and(|state| state.pressed_within(self.tick, ticks))
+    }
+
+    /// Whether a mouse button is released in the last amount of update ticks.
+    #[inline]
+    #[must_use]
+    pub(crate) fn mouse_released_within(&self, mouse_button: MouseButton, ticks: u32) -> bool {
+        self.mouse_buttons
+            .get(&mouse_button)
+            .is_some_and(|state| state.released_within(self.tick, ticks))
+    }
+
+    /// Absolute mouse position if on screen.
+    pub(crate) const fn mouse(&self) -> Option<(f32, f32)> {
+        self.mouse
+    }
+
+    /// How much the mouse moved on the device this update tick.
+    pub(crate) const fn mouse_motion(&self) -> (f32, f32) {
+        self.mouse_motion
+    }
+
+    /// How much the mouse scrolled this update tick.
+    pub(crate) const fn scroll_diff(&self) -> (f32, f32) {
+        (self.scroll_delta_x, self.scroll_delta_y)
+    }
+
+    /// IDs of all fingers touching the screen, including the ones lifted this update tick.
+    pub(crate) fn touch_ids(&self) -> SmallVec<[u64; 4]> {
+        self.touches.iter().map(|touch| touch.id).collect()
+    }
+
+    /// Position of a finger touching the screen on the buffer.
+    pub(crate) fn touch_position(&self, id: u64) -> Option<(f32, f32)> {
+        self.touches
+            .iter()
+            .find(|touch| touch.id == id)
+            .and_then(|touch| touch.position)
+    }
+
+    /// Whether a finger started touching the screen this update tick.
+    pub(crate) fn touch_pressed(&self, id: u64) -> bool {
+        self.touches
+            .iter()
+            .any(|touch| touch.id == id && touch.is_pressed)
+    }
+
+    /// Whether a finger is lifted from the screen this update tick.
+    pub(crate) fn touch_released(&self, id: u64) -> bool {
+        self.touches
+            .iter()
+            .any(|touch| touch.id == id && touch.is_released)
+    }
+
+    /// Whether a finger is touching the screen.
+    pub(crate) fn touch_held(&self, id: u64) -> bool {
+        self.touches
+            .iter()
+            .any(|touch| touch.id == id && touch.is_down)
+    }
+
+    /// Let the first finger touching the screen act as the left mouse button.
+    pub(crate) fn set_touch_emulates_mouse(&mut self, emulate: bool) {
+        self.touch_emulates_mouse = emulate;
+
+        // Release the emulated mouse button
+        if !emulate && self.mouse_touch.take().is_some() {
+            self.apply(InputEvent::MouseReleased(mouse_button_index(
+                MouseButton::Left,
+            )));
+        }
+    }
+
+    /// Get a touch that's still touching the screen.
+    fn touch(&mut self, id: u64) -> Option<&mut Touch> {
+        self.touches
+            .iter_mut()
+            .find(|touch| touch.id == id && touch.is_down)
+    }
+
+    /// Check the key pressed state for a keyboard button.
+    #[inline]
+    #[must_use]
+    pub(crate) fn key_pressed(&self, key: KeyCode) -> bool {
+        let Some(key_button_state) = self.keys.get(&key) else {
+            return false;
+        };
+
+        key_button_state.pressed()
+    }
+
+    /// Check the key released state for a keyboard button.
+    #[inline]
+    #[must_use]
+    pub(crate) fn key_released(&self, key: KeyCode) -> bool {
+        let Some(key_button_state) = self.keys.get(&key) else {
+            return false;
+        };
+
+        key_button_state.released()
+    }
+
+    /// Check the key pressed state for a keyboard button, including presses repeated by the OS when holding it.
+    #[inline]
+    #[must_use]
+    pub(crate) fn key_pressed_or_repeated(&self, key: KeyCode) -> bool {
+        let Some(key_button_state) = self.keys.get(&key) else {
+            return false;
+        };
+
+        key_button_state.pressed_or_repeated()
+    }
+
+    /// How many update ticks a key is held, including the current one.
+    #[inline]
+    #[must_use]
+    pub(crate) fn key_held_duration(&self, key: KeyCode) -> u32 {
+        self.keys
+            .get(&key)
+            .map_or(0, |state| state.held_duration(self.tick))
+    }
+
+    /// Whether a key is pressed in the last amount of update ticks.
+    #[inline]
+    #[must_use]
+    pub(crate) fn key_pressed_within(&self, key: KeyCode, ticks: u32) -> bool {
+        self.keys
+            .get(&key)
+            .is_some_and(|state| state.pressed_within(self.tick, ticks))
+    }
+
+    /// Whether a key is released in the last amount of update ticks.
+    #[inline]
+    #[must_use]
+    pub(crate) fn key_released_within(&self, key: KeyCode, ticks: u32) -> bool {
+        self.keys
+            .get(&key)
+            .is_some_and(|state| state.released_within(self.tick, ticks))
+    }
+
+    /// Characters typed this update tick.
+    pub(crate) fn text_input(&self) -> &str {
+        &self.typed_text
+    }
+
+    /// Check the key held state for a keyboard button.
+    #[inline]
+    #[must_use]
+    pub(crate) fn key_held(&self, key_button: KeyCode) -> bool {
+        let Some(key_button_state) = self.keys.get(&key_button) else {
+            return false;
+        };
+
+        key_button_state.held()
+    }
+
+    /// List connected gamepads.
+    #[inline]
+    #[must_use]
+    pub(crate) fn gamepads_ids(&self) -> SmallVec<[GamepadId; 4]> {
+        self.gamepads.keys().copied().collect()
+    }
+
+    /// List gamepads connected this update tick.
+    #[inline]
+    #[must_use]
+    pub(crate) fn gamepads_connected(&self) -> SmallVec<[GamepadId; 4]> {
+        self.gamepads_connected.clone()
+    }
+
+    /// List gamepads disconnected this update tick.
+    #[inline]
+    #[must_use]
+    pub(crate) fn gamepads_disconnected(&self) -> SmallVec<[GamepadId; 4]> {
+        self.gamepads_disconnected.clone()
+    }
+
+    /// Name of a gamepad.
+    #[inline]
+    #[must_use]
+    pub(crate) fn gamepad_name(&self, gamepad_id: GamepadId) -> Option<String> {
+        self.connected_gamepad(gamepad_id)
+            .map(|gamepad| gamepad.name().to_owned())
+    }
+
+    /// Name of the mapping of a gamepad.
+    #[inline]
+    #[must_use]
+    pub(crate) fn gamepad_mapping_name(&self, gamepad_id: GamepadId) -> Option<String> {
+        self.connected_gamepad(gamepad_id)
+            .and_then(|gamepad| gamepad.map_name().map(ToOwned::to_owned))
+    }
+
+    /// UUID of a gamepad.
+    #[inline]
+    #[must_use]
+    pub(crate) fn gamepad_uuid(&self, gamepad_id: GamepadId) -> Option<[u8; 16]> {
+        self.connected_gamepad(gamepad_id)
+            .map(|gamepad| gamepad.uuid())
+    }
+
+    /// Rumble both motors of a gamepad, replacing the previous rumble.
+    pub(crate) fn gamepad_rumble(
+        &mut self,
+        gamepad_id: GamepadId,
+        strength: f32,
+        duration: f32,
+    ) -> bool {
+        // Stop the previous rumble
+        self.rumbles.remove(&gamepad_id);
+
+        // Gamepad must support force feedback
+        let Some(gilrs_id) = self
+            .connected_gamepad(gamepad_id)
+            .filter(Gamepad::is_ff_supported)
+            .map(|gamepad| gamepad.id())
+        else {
+            return false;
+        };
+
+        // Nothing to play
+        if strength <= 0.0 || duration <= 0.0 {
+            return true;
+        }
+
+        // Play both the strong and the weak motor for the duration
+        let magnitude = (strength.min(1.0) * f32::from(u16::MAX)) as u16;
+        let ticks = Ticks::from_ms((duration * 1000.0) as u32);
+        let scheduling = EffectReplay {
+            play_for: ticks,
+            ..Default::default()
+        };
+        let effect = EffectBuilder::new()
+            .add_effect(BaseEffect {
+                kind: BaseEffectType::Strong { magnitude },
+                scheduling,
+                ..Default::default()
+            })
+            .add_effect(BaseEffect {
+                kind: BaseEffectType::Weak { magnitude },
+                scheduling,
+                ..Default::default()
+            })
+            .gamepads(&[gilrs_id])
+            .repeat(Repeat::For(ticks))
+            .finish(&mut self.gilrs);
+
+        // Keep the effect alive until it's replaced
+        match effect.and_then(|effect| effect.play().map(|()| effect)) {
+            Ok(effect) => {
+                self.rumbles.insert(gamepad_id, effect);
+
+                true
+            }
+            Err(err) => {
+                eprintln!("Error playing gamepad rumble: {err}");
+
+                false
+            }
+        }
+    }
+
+    /// Whether a gamepad button goes from "not pressed" to "pressed".
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_pressed(&self, gamepad_id: GamepadId, button: Button) -> Option<bool> {
+        self.gamepads.get(&gamepad_id).map(|gamepad| {
+            gamepad
+                .buttons
+                .get(&button)
+                .is_some_and(ButtonState::pressed)
+        })
+    }
+
+    /// Whether a gamepad button goes from "pressed" to "not pressed".
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_released(&self, gamepad_id: GamepadId, button: Button) -> Option<bool> {
+        self.gamepads.get(&gamepad_id).map(|gamepad| {
+            gamepad
+                .buttons
+                .get(&button)
+                .is_some_and(ButtonState::released)
+        })
+    }
+
+    /// Whether a gamepad button is in a "pressed" state.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_held(&self, gamepad_id: GamepadId, button: Button) -> Option<bool> {
+        self.gamepads
+            .get(&gamepad_id)
+            .map(|gamepad| gamepad.buttons.get(&button).is_some_and(ButtonState::held))
+    }
+
+    /// How many update ticks a gamepad button is held, including the current one.
+    #[inline]
+    #[must_use]
+    pub(crate) fn gamepad_button_held_duration(
+        &self,
+        gamepad_id: GamepadId,
+        button: Button,
+    ) -> Option<u32> {
+        self.gamepads.get(&gamepad_id).map(|gamepad| {
+            gamepad
+                .buttons
+                .get(&button)
+                .map_or(0, |state| state.held_duration(self.tick))
+        })
+    }
+
+    /// Whether a gamepad button is pressed in the last amount of update ticks.
+    #[inline]
+    #[must_use]
+    pub(crate) fn gamepad_button_pressed_within(
+        &self,
+        gamepad_id: GamepadId,
+        button: Button,
+        ticks: u32,
+    ) -> Option<bool> {
+        self.gamepads.get(&gamepad_id).map(|gamepad| {
+            gamepad
+                .buttons
+                .get(&button)
+                .is_some_and(|state| state.pressed_within(self.tick, ticks))
+        })
+    }
+
+    /// Whether a gamepad button is released in the last amount of update ticks.
+    #[inline]
+    #[must_use]
+    pub(crate) fn gamepad_button_released_within(
+        &self,
+        gamepad_id: GamepadId,
+        button: Button,
+        ticks: u32,
+    ) -> Option<bool> {
+        self.gamepads.get(&gamepad_id).map(|gamepad| {
+            gamepad
+                .buttons
+                .get(&button)
+                .is_some_and(|state| state.released_within(self.tick, ticks))
+        })
+    }
+
+    /// "Value" of a gamepad button between 0.0 and 1.0.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_button_value(&self, gamepad_id: GamepadId, button: Button) -> Option<f32> {
+        self.gamepads.get(&gamepad_id).map(|gamepad| {
+            gamepad
+                .button_values
+                .get(&button)
+                .copied()
+                .unwrap_or_default()
+        })
+    }
+
+    /// "Value" of a gamepad element between -1.0 and 1.0.
+    #[inline]
+    #[must_use]
+    pub fn gamepad_axis(&self, gamepad_id: GamepadId, axis: Axis) -> Option<f32> {
+        self.gamepads
+            .get(&gamepad_id)
+            .map(|gamepad| gamepad.axes.get(&axis).copied().unwrap_or_default())
+    }
+
+    /// Position of a gamepad stick with the deadzones and response curve applied.
+    #[inline]
+    #[must_use]
+    pub(crate) fn gamepad_stick(&self, gamepad_id: GamepadId, stick: Stick) -> (f32, f32) {
+        let (x_axis, y_axis) = stick.axes();
+        let x = self.gamepad_axis(gamepad_id, x_axis).unwrap_or_default();
+        let y = self.gamepad_axis(gamepad_id, y_axis).unwrap_or_default();
+
+        self.gamepad_stick_settings
+            .get(&gamepad_id)
+            .unwrap_or(&self.stick_settings)
+            .apply(x, y)
+    }
+
+    /// Set the stick deadzones and response curve for all gamepads.
+    #[inline]
+    pub(crate) const fn set_stick_settings(&mut self, settings: StickSettings) {
+        self.stick_settings = settings;
+    }
+
+    /// Overwrite the stick deadzones and response curve for a single gamepad, `None` uses the settings for all gamepads again.
+    #[inline]
+    pub(crate) fn set_gamepad_stick_settings(
+        &mut self,
+        gamepad_id: GamepadId,
+        settings: Option<StickSettings>,
+    ) {
+        match settings {
+            Some(settings) => {
+                self.gamepad_stick_settings.insert(gamepad_id, settings);
+            }
+            None => {
+                self.gamepad_stick_settings.remove(&gamepad_id);
+            }
+        }
+    }
+
+    /// Get a real connected gamepad for its metadata, ignoring all of them when replaying a recording.
+    fn connected_gamepad(&self, gamepad_id: GamepadId) -> Option<Gamepad<'_>> {
+        if self.replay.is_some() {
+            return None;
+        }
+
+        self.gilrs
+            .gamepads()
+            .find_map(|(id, gamepad)| (GamepadId::from(id) == gamepad_id).then_some(gamepad))
+    }
+
+    /// Take the state of the real gamepads, for returning to them after replaying a recording.
+    fn restore_gamepads(&mut self) {
+        self.gamepads = GamepadState::all_connected(&self.gilrs, self.tick);
+    }
+
+    /// Start recording all keyboard, mouse, touch and gamepad input, starting with the current state.
+    pub(crate) fn start_recording(&mut self) {
+        let mut recording = Recorder::default();
+
+        // Store the state at the start of the recording
+        for (key, state) in &self.keys {
+            if let Some(key) = key_index(*key).filter(|_| state.held()) {
+                recording.push(InputEvent::KeyHeld(key));
+            }
+        }
+        for (button, state) in &self.mouse_buttons {
+            if state.held() {
+                recording.push(InputEvent::MouseHeld(mouse_button_index(*button)));
+            }
+        }
+        recording.push(InputEvent::MouseMoved(self.mouse));
+        for (gamepad_id, gamepad) in &self.gamepads {
+            recording.push_gamepad(*gamepad_id, GamepadEvent::Connected);
+
+            for (button, state) in &gamepad.buttons {
+                if let Some(button) = button_index(*button).filter(|_| state.held()) {
+                    recording.push_gamepad(*gamepad_id, GamepadEvent::ButtonHeld(button));
+                }
+            }
+            for (button, value) in &gamepad.button_values {
+                if let Some(button) = button_index(*button).filter(|_| *value != 0.0) {
+                    recording
+                        .push_gamepad(*gamepad_id, GamepadEvent::ButtonChanged(button, *value));
+                }
+            }
+            for (axis, value) in &gamepad.axes {
+                if let Some(axis) = axis_index(*axis).filter(|_| *value != 0.0) {
+                    recording.push_gamepad(*gamepad_id, GamepadEvent::AxisChanged(axis, *value));
+                }
+            }
+        }
+
+        self.recording = Some(recording);
+    }
+
+    /// Stop recording and return the recording.
+    pub(crate) fn stop_recording(&mut self) -> InputRecording {
+        self.recording
+            .take()
+            .map(|recorder| recorder.recording)
+            .unwrap_or_default()
+    }
+
+    /// Whether input is being recorded.
+    pub(crate) const fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Start replaying a recording, ignoring all real input until it's finished.
+    pub(crate) fn start_replay(&mut self, recording: InputRecording) {
+        self.reset_state();
+
+        self.replay = Some(Replay {
+            recording,
+            tick: 0,
+            next_event: 0,
+        });
+
+        // Apply the events recorded before the first update tick
+        self.replay_tick();
+    }
+
+    /// Stop replaying a recording, returning to the real input.
+    pub(crate) fn stop_replay(&mut self) {
+        if self.replay.take().is_some() {
+            self.reset_state();
+            self.restore_gamepads();
+        }
+    }
+
+    /// Whether a recording is being replayed.
+    pub(crate) const fn is_replaying(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    /// Apply the events of the next tick of the recording being replayed.
+    fn replay_tick(&mut self) {
+        // Take the replay so the events can be applied
+        let Some(mut replay) = self.replay.take() else {
+            return;
+        };
+
+        // Stop when all ticks have been replayed
+        if replay.tick > replay.recording.ticks() {
+            self.reset_state();
+            self.restore_gamepads();
+
+            return;
+        }
+
+        while let Some((tick, event)) = replay.recording.events().get(replay.next_event) {
+            if *tick != replay.tick {
+                break;
+            }
+
+            self.apply(event.clone());
+            replay.next_event += 1;
+        }
+
+        replay.tick += 1;
+        self.replay = Some(replay);
+    }
+
+    /// Release all keys and buttons, and forget all gamepads.
+    fn reset_state(&mut self) {
+        self.gamepads.clear();
+        self.gamepads_connected.clear();
+        self.gamepads_disconnected.clear();
+        self.rumbles.clear();
+        self.keys.clear();
+        self.mouse_buttons.clear();
+        self.mouse = None;
+        self.mouse_motion = (0.0, 0.0);
+        self.scroll_delta_x = 0.0;
+        self.scroll_delta_y = 0.0;
+        self.typed_text.clear();
+        self.touches.clear();
+        self.mouse_touch = None;
+    }
+
+    /// Replace all action and axis bindings.
+    pub(crate) fn set_bindings(&mut self, bindings: InputBindings) {
+        self.bindings = bindings;
+
+        self.sync_actions_held();
+    }
+
+    /// All action and axis bindings.
+    pub(crate) const fn bindings(&self) -> &InputBindings {
+        &self.bindings
+    }
+
+    /// Add a binding to an action.
+    pub(crate) fn bind_action(&mut self, action: String, binding: Binding) {
+        self.bindings
+            .actions
+            .entry(action)
+            .or_default()
+            .push(binding);
+
+        self.sync_actions_held();
+    }
+
+    /// Remove all bindings from an action.
+    pub(crate) fn unbind_action(&mut self, action: &str) {
+        self.bindings.actions.remove(action);
+
+        self.sync_actions_held();
+    }
+
+    /// Add a pair of bindings to an axis.
+    pub(crate) fn bind_axis(&mut self, axis: String, negative: Binding, positive: Binding) {
+        let axis = self.bindings.axes.entry(axis).or_default();
+        axis.negative.push(negative);
+        axis.positive.push(positive);
+    }
+
+    /// Remove all bindings from an axis.
+    pub(crate) fn unbind_axis(&mut self, axis: &str) {
+        self.bindings.axes.remove(axis);
+    }
+
+    /// Whether any binding of an action is held.
+    #[inline]
+    #[must_use]
+    pub(crate) fn action_held(&self, action: &str) -> bool {
+        !self.capturing
+            && self.bindings.actions.get(action).is_some_and(|bindings| {
+                bindings
+                    .iter()
+                    .any(|binding| self.binding_value(*binding) > ACTION_THRESHOLD)
+            })
+    }
+
+    /// Whether an action goes from "not held" to "held".
+    #[inline]
+    #[must_use]
+    pub(crate) fn action_pressed(&self, action: &str) -> bool {
+        // Also handle keys pressed and released again within a single update tick
+        !self.action_was_held(action) && (self.action_held(action) || self.action_tapped(action))
+    }
+
+    /// Whether an action goes from "held" to "not held".
+    #[inline]
+    #[must_use]
+    pub(crate) fn action_released(&self, action: &str) -> bool {
+        // Also handle keys pressed and released again within a single update tick
+        !self.action_held(action) && (self.action_was_held(action) || self.action_tapped(action))
+    }
+
+    /// Value of an axis between `-1.0` and `1.0`.
+    #[inline]
+    #[must_use]
+    pub(crate) fn action_axis(&self, axis: &str) -> f32 {
+        if self.capturing {
+            return 0.0;
+        }
+
+        let Some(axis) = self.bindings.axes.get(axis) else {
+            return 0.0;
+        };
+
+        // Use the strongest binding in each direction
+        let strongest = |bindings: &[Binding]| {
+            bindings
+                .iter()
+                .map(|binding| self.binding_value(*binding))
+                .fold(0.0, f32::max)
+        };
+
+        (strongest(&axis.positive) - strongest(&axis.negative)).clamp(-1.0, 1.0)
+    }
+
+    /// Start capturing the next pressed input, disabling all actions and axes until then.
+    pub(crate) const fn capture_next_input(&mut self) {
+        self.capturing = true;
+        self.captured = None;
+    }
+
+    /// Take the captured input.
+    pub(crate) const fn take_captured(&mut self) -> Option<Binding> {
+        self.captured.take()
+    }
+
+    /// Store an input when capturing.
+    const fn capture(&mut self, binding: Binding) {
+        if self.capturing {
+            self.capturing = false;
+            self.captured = Some(binding);
+        }
+    }
+
+    /// Whether the action was held during the previous update tick.
+    fn action_was_held(&self, action: &str) -> bool {
+        self.actions_held.get(action).copied().unwrap_or_default()
+    }
+
+    /// Whether any key or mouse binding of an action got pressed this update tick.
+    fn action_tapped(&self, action: &str) -> bool {
+        !self.capturing
+            && self.bindings.actions.get(action).is_some_and(|bindings| {
+                bindings.iter().any(|binding| match binding {
+                    Binding::Key(key) => self.key_pressed(*key),
+                    Binding::Mouse(button) => self.mouse_pressed(*button),
+                    _ => false,
+                })
+            })
+    }
+
+    /// How far a binding is engaged between `0.0` and `1.0`, using the strongest of all connected gamepads.
+    fn binding_value(&self, binding: Binding) -> f32 {
+        // Combine the values of all gamepads
+        let gamepads = |value: &dyn Fn(GamepadId) -> Option<f32>| {
+            self.gamepads_ids()
+                .into_iter()
+                .filter_map(value)
+                .fold(0.0, f32::max)
+        };
+
+        match binding {
+            Binding::Key(key) => f32::from(u8::from(self.key_held(key))),
+            Binding::Mouse(button) => f32::from(u8::from(self.mouse_held(button))),
+            Binding::GamepadButton(button) => gamepads(&|id| {
+                // Digital buttons don't always report a value
+                self.gamepad_button_value(id, button)
+                    .filter(|value| *value > 0.0)
+                    .or_else(|| {
+                        self.gamepad_button_held(id, button)
+                            .map(|held| f32::from(u8::from(held)))
+                    })
+            }),
+            Binding::GamepadAxisPositive(axis) => gamepads(&|id| self.gamepad_axis(id, axis)),
+            Binding::GamepadAxisNegative(axis) => {
+                gamepads(&|id| self.gamepad_axis(id, axis).map(|value| -value))
+            }
+        }
+    }
+
+    /// Keep track of the held state of every bound action.
+    fn sync_actions_held(&mut self) {
+        self.actions_held
+            .retain(|action, _held| self.bindings.actions.contains_key(action));
+        for action in self.bindings.actions.keys() {
+            if !self.actions_held.contains_key(action) {
+                self.actions_held.insert(action.clone(), false);
+            }
+        }
+    }
+}
+
+/// Define lookup functions for the names of enum variants, optionally with a list of all variants.
+macro_rules! variant_names {
+    ($from_name:ident, $to_name:ident, $index:ident, $all:ident: $ty:ident { $($variant:ident),* $(,)? }) => {
+        /// All variants, the index is used for compact storage.
+        const $all: &[$ty] = &[$($ty::$variant),*];
+
+        /// Get the index of the variant in the list of all variants, `None` for variants that aren't listed.
+        fn $index(value: $ty) -> Option<u16> {
+            // Build the lookup table once
+            static INDICES: OnceLock<HashMap<$ty, u16>> = OnceLock::new();
+
+            INDICES
+                .get_or_init(|| $all.iter().copied().zip(0..).collect())
+                .get(&value)
+                .copied()
+        }
+
+        variant_names!($from_name, $to_name: $ty { $($variant),* });
+    };
+    ($from_name:ident, $to_name:ident: $ty:ident { $($variant:ident),* $(,)? }) => {
+        /// Get the value from the name of the variant.
+        fn $from_name(name: &str) -> Option<$ty> {
+            match name {
+                $(stringify!($variant) => Some($ty::$variant),)*
+                _ => None,
+            }
+        }
+
+        /// Get the name of the variant, `"Unknown"` for variants that can't be parsed.
+        const fn $to_name(value: $ty) -> &'static str {
+            match value {
+                $($ty::$variant => stringify!($variant),)*
+                #[allow(unreachable_patterns)]
+                _ => "Unknown",
+            }
+        }
+    };
+}
+
+variant_names!(key_from_name, key_name, key_index, KEYS: KeyCode {
+    Backquote, Backslash, BracketLeft, BracketRight, Comma, Digit0, Digit1, Digit2, Digit3, Digit4,
+    Digit5, Digit6, Digit7, Digit8, Digit9, Equal, IntlBackslash, IntlRo, IntlYen, KeyA, KeyB,
+    KeyC, KeyD, KeyE, KeyF, KeyG, KeyH, KeyI, KeyJ, KeyK, KeyL, KeyM, KeyN, KeyO, KeyP, KeyQ,
+    KeyR, KeyS, KeyT, KeyU, KeyV, KeyW, KeyX, KeyY, KeyZ, Minus, Period, Quote, Semicolon, Slash,
+    AltLeft, AltRight, Backspace, CapsLock, ContextMenu, ControlLeft, ControlRight, Enter,
+    SuperLeft, SuperRight, ShiftLeft, ShiftRight, Space, Tab, Convert, KanaMode, Lang1, Lang2,
+    Lang3, Lang4, Lang5, NonConvert, Delete, End, Help, Home, Insert, PageDown, PageUp, ArrowDown,
+    ArrowLeft, ArrowRight, ArrowUp, NumLock, Numpad0, Numpad1, Numpad2, Numpad3, Numpad4,
+    Numpad5, Numpad6, Numpad7, Numpad8, Numpad9, NumpadAdd, NumpadBackspace, NumpadClear,
+    NumpadClearEntry, NumpadComma, NumpadDecimal, NumpadDivide, NumpadEnter, NumpadEqual,
+    NumpadHash, NumpadMemoryAdd, NumpadMemoryClear, NumpadMemoryRecall, NumpadMemoryStore,
+    NumpadMemorySubtract, NumpadMultiply, NumpadParenLeft, NumpadParenRight, NumpadStar,
+    NumpadSubtract, Escape, Fn, FnLock, PrintScreen, ScrollLock, Pause, BrowserBack,
+    BrowserFavorites, BrowserForward, BrowserHome, BrowserRefresh, BrowserSearch, BrowserStop,
+    Eject, LaunchApp1, LaunchApp2, LaunchMail, MediaPlayPause, MediaSelect, MediaStop,
+    MediaTrackNext, MediaTrackPrevious, Power, Sleep, AudioVolumeDown, AudioVolumeMute,
+    AudioVolumeUp, WakeUp, Meta, Hyper, Turbo, Abort, Resume, Suspend, Again, Copy, Cut, Find,
+    Open, Paste, Props, Select, Undo, Hiragana, Katakana, F1, F2, F3, F4, F5, F6, F7, F8, F9, F10,
+    F11, F12, F13, F14, F15, F16, F17, F18, F19, F20, F21, F22, F23, F24, F25, F26, F27, F28, F29,
+    F30, F31, F32, F33, F34, F35,
+});
+
+variant_names!(button_from_name, button_name, button_index, BUTTONS: Button {
+    South, East, North, West, C, Z, LeftTrigger, LeftTrigger2, RightTrigger, RightTrigger2, Select,
+    Start, Mode, LeftThumb, RightThumb, DPadUp, DPadDown, DPadLeft, DPadRight,
+});
+
+variant_names!(axis_from_name, axis_name, axis_index, AXES: Axis {
+    LeftStickX, LeftStickY, LeftZ, RightStickX, RightStickY, RightZ, DPadX, DPadY,
+});
+
+/// Convert a mouse button to a number for compact storage.
+const fn mouse_button_index(button: MouseButton) -> u16 {
+    match button {
+        MouseButton::Left => 0,
+        MouseButton::Right => 1,
+        MouseButton::Middle => 2,
+        MouseButton::Back => 3,
+        MouseButton::Forward => 4,
+        MouseButton::Other(button) => button.saturating_add(5),
+    }
+}
+
+/// Convert a number created with [`mouse_button_index`] back to a mouse button.
+const fn mouse_button_from_index(index: u16) -> MouseButton {
+    match index {
+        0 => MouseButton::Left,
+        1 => MouseButton::Right,
+        2 => MouseButton::Middle,
+        3 => MouseButton::Back,
+        4 => MouseButton::Forward,
+        other => MouseButton::Other(other - 5),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use winit::event::MouseButton;
+
+    use super::{AXES, BUTTONS, Binding, KEYS};
+
+    #[test]
+    fn binding_string_round_trip() {
+        let bindings = KEYS
+            .iter()
+            .copied()
+            .map(Binding::Key)
+            .chain(
+                [
+                    MouseButton::Left,
+                    MouseButton::Right,
+                    MouseButton::Middle,
+                    MouseButton::Back,
+                    MouseButton::Forward,
+                    MouseButton::Other(4),
+                ]
+                .map(Binding::Mouse),
+            )
+            .chain(BUTTONS.iter().copied().map(Binding::GamepadButton))
+            .chain(AXES.iter().copied().map(Binding::GamepadAxisPositive))
+            .chain(AXES.iter().copied().map(Binding::GamepadAxisNegative));
+
+        for binding in bindings {
+            assert_eq!(Binding::parse(&binding.to_string()), Some(binding));
+        }
+    }
+
+    #[test]
+    fn binding_parse() {
+        assert_eq!(
+            Binding::parse("GamepadAxis(-LeftStickY)"),
+            Some(Binding::GamepadAxisNegative(gilrs::Axis::LeftStickY))
+        );
+        assert_eq!(Binding::parse("Key(NotAKey)"), None);
+        assert_eq!(Binding::parse("GamepadAxis(LeftStickY)"), None);
+        assert_eq!(Binding::parse("Key(Space"), None);
+    }
+}
@@ -0,0 +1,179 @@
+//! Record input state changes per update tick so they can be replayed.
+
+use nanoserde::{DeBin, SerBin};
+
+use super::gamepad::{GamepadEvent, GamepadId};
+
+/// Single change of the input state.
+///
+/// Keys, mouse buttons and gamepads are stored as indices to keep the recording compact.
+#[derive(Debug, Clone, PartialEq, DeBin, SerBin)]
+pub enum InputEvent {
+    /// Key goes from "not pressed" to "pressed".
+    KeyPressed(u16),
+    /// Key goes from "pressed" to "not pressed".
+    KeyReleased(u16),
+    /// Key press is repeated by the OS.
+    KeyRepeated(u16),
+    /// Key was already held when the recording started.
+    KeyHeld(u16),
+    /// Mouse button goes from "not pressed" to "pressed".
+    MousePressed(u16),
+    /// Mouse button goes from "pressed" to "not pressed".
+    MouseReleased(u16),
+    /// Mouse button was already held when the recording started.
+    MouseHeld(u16),
+    /// Mouse moved to a position on the buffer, or left it.
+    MouseMoved(Option<(f32, f32)>),
+    /// Mouse scroll wheel moved.
+    Scrolled(f32, f32),
+    /// Text is typed.
+    Text(String),
+    /// Finger starts touching the screen at a position on the buffer.
+    TouchStarted(u64, Option<(f32, f32)>),
+    /// Finger touching the screen moved to a position on the buffer.
+    TouchMoved(u64, Option<(f32, f32)>),
+    /// Finger is lifted from the screen.
+    TouchEnded(u64),
+    /// Mouse moved on the device, independent of the cursor.
+    MouseMotion(f32, f32),
+    /// State of a gamepad changed, the gamepads are numbered in the order they were connected during the recording.
+    Gamepad(u16, GamepadEvent),
+}
+
+/// Keyboard, mouse, touch and gamepad input recorded per update tick.
+///
+/// Create with [`crate::Context::start_recording`] and [`crate::Context::stop_recording`], and replay with [`crate::Context::replay`].
+#[derive(Debug, Clone, Default, PartialEq, DeBin, SerBin)]
+pub struct InputRecording {
+    /// How many update ticks are recorded.
+    ticks: u32,
+    /// All changes with the update tick they happened in.
+    events: Vec<(u32, InputEvent)>,
+}
+
+impl InputRecording {
+    /// Store a change in the current update tick.
+    pub(crate) fn push(&mut self, event: InputEvent) {
+        self.events.push((self.ticks, event));
+    }
+
+    /// Move to the next update tick.
+    pub(crate) const fn next_tick(&mut self) {
+        self.ticks += 1;
+    }
+
+    /// All changes with the update tick they happened in.
+    pub(crate) fn events(&self) -> &[(u32, InputEvent)] {
+        &self.events
+    }
+
+    /// How many update ticks are recorded.
+    ///
+    /// # Returns
+    ///
+    /// - Duration of the recording in update ticks, multiply with [`crate::Config::update_delta_time`] to get the duration in seconds.
+    #[inline]
+    #[must_use]
+    pub const fn ticks(&self) -> u32 {
+        self.ticks
+    }
+
+    /// Serialize to a compact binary format, so it can be saved to a file.
+    ///
+    /// # Returns
+    ///
+    /// - Bytes that can be loaded again with [`Self::from_bytes`].
+    #[inline]
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.serialize_bin()
+    }
+
+    /// Deserialize from the binary format created with [`Self::to_bytes`].
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Serialized recording.
+    ///
+    /// # Returns
+    ///
+    /// - `None` when the bytes are not a valid recording.
+    #[inline]
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::deserialize_bin(bytes).ok()
+    }
+}
+
+/// State of a recording in progress.
+#[derive(Default)]
+pub(crate) struct Recorder {
+    /// Changes recorded so far.
+    pub(crate) recording: InputRecording,
+    /// Gamepads in the order they were connected, the index is stored in the recording.
+    gamepads: Vec<GamepadId>,
+}
+
+impl Recorder {
+    /// Store a change in the current update tick.
+    pub(crate) fn push(&mut self, event: InputEvent) {
+        self.recording.push(event);
+    }
+
+    /// Store a change of a gamepad in the current update tick.
+    pub(crate) fn push_gamepad(&mut self, gamepad_id: GamepadId, event: GamepadEvent) {
+        // Number the gamepads independent of their IDs, which are only valid while the game is running
+        let slot = self
+            .gamepads
+            .iter()
+            .position(|id| *id == gamepad_id)
+            .unwrap_or_else(|| {
+                self.gamepads.push(gamepad_id);
+
+                self.gamepads.len() - 1
+            });
+
+        if let Ok(slot) = u16::try_from(slot) {
+            self.recording.push(InputEvent::Gamepad(slot, event));
+        }
+    }
+}
+
+/// State of a recording being replayed.
+pub(crate) struct Replay {
+    /// Recording being replayed.
+    pub(crate) recording: InputRecording,
+    /// Update tick of which the events will be applied next.
+    pub(crate) tick: u32,
+    /// Index of the next event to apply.
+    pub(crate) next_event: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GamepadEvent, InputEvent, InputRecording};
+
+    #[test]
+    fn bytes_round_trip() {
+        let mut recording = InputRecording::default();
+        recording.push(InputEvent::KeyHeld(3));
+        recording.next_tick();
+        recording.push(InputEvent::KeyPressed(12));
+        recording.push(InputEvent::MouseMoved(Some((10.5, 20.25))));
+        recording.push(InputEvent::MouseMoved(None));
+        recording.push(InputEvent::Text("héllo".to_owned()));
+        recording.next_tick();
+        recording.push(InputEvent::TouchStarted(1, Some((1.0, 2.0))));
+        recording.push(InputEvent::Gamepad(0, GamepadEvent::Connected));
+        recording.push(InputEvent::Gamepad(1, GamepadEvent::AxisChanged(2, -0.5)));
+        recording.next_tick();
+
+        let bytes = recording.to_bytes();
+        assert_eq!(InputRecording::from_bytes(&bytes), Some(recording.clone()));
+        assert_eq!(recording.ticks(), 3);
+
+        // A truncated recording is not valid
+        assert_eq!(InputRecording::from_bytes(&bytes[..bytes.len() - 1]), None);
+    }
+}
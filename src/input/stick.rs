@@ -0,0 +1,280 @@
+//! Deadzones and response curves for gamepad sticks.
+
+use gilrs::Axis;
+
+/// Analog stick on a gamepad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Stick {
+    /// Left stick, uses the [`crate::GamepadAxis::LeftStickX`] and [`crate::GamepadAxis::LeftStickY`] axes.
+    Left,
+    /// Right stick, uses the [`crate::GamepadAxis::RightStickX`] and [`crate::GamepadAxis::RightStickY`] axes.
+    Right,
+}
+
+impl Stick {
+    /// Horizontal and vertical axes of the stick.
+    pub(crate) const fn axes(self) -> (Axis, Axis) {
+        match self {
+            Self::Left => (Axis::LeftStickX, Axis::LeftStickY),
+            Self::Right => (Axis::RightStickX, Axis::RightStickY),
+        }
+    }
+}
+
+/// How the inner and outer deadzones of a stick are measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum DeadzoneShape {
+    /// Measure the distance of the stick from the center.
+    ///
+    /// Keeps the direction of the stick intact, which feels best for moving characters.
+    #[default]
+    Radial,
+    /// Measure both axes separately.
+    ///
+    /// Snaps to the horizontal and vertical directions when the stick is slightly off, which feels best for menus and grid movement.
+    Axial,
+}
+
+/// How the position of the stick between the inner and outer deadzones is mapped to the output value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[non_exhaustive]
+pub enum ResponseCurve {
+    /// Output is the same as the input.
+    #[default]
+    Linear,
+    /// Input is squared, giving more precision for small movements.
+    Quadratic,
+    /// Input is cubed, giving even more precision for small movements.
+    Cubic,
+    /// Input is raised to a custom exponent, values below `1.0` make the stick more sensitive.
+    ///
+    /// The exponent must be larger than `0.0`, smaller values are clamped to a tiny positive exponent.
+    Power(f32),
+}
+
+impl ResponseCurve {
+    /// Map a value between `0.0` and `1.0`.
+    fn apply(self, value: f32) -> f32 {
+        match self {
+            Self::Linear => value,
+            Self::Quadratic => value * value,
+            Self::Cubic => value * value * value,
+            Self::Power(exponent) => value.powf(exponent.max(f32::EPSILON)),
+        }
+    }
+}
+
+/// Deadzones and response curve applied to gamepad sticks.
+///
+/// Set for all gamepads with [`crate::Context::set_gamepad_stick_settings`] or for a single gamepad with [`crate::Context::set_gamepad_stick_settings_for`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(clippy::exhaustive_structs)]
+pub struct StickSettings {
+    /// How the deadzones are measured.
+    ///
+    /// Defaults to [`DeadzoneShape::Radial`].
+    pub shape: DeadzoneShape,
+    /// Distance between `0.0` and `1.0` from the center below which the stick is ignored, to prevent drift.
+    ///
+    /// Defaults to `0.15`.
+    pub inner_deadzone: f32,
+    /// Distance between `0.0` and `1.0` from the center above which the stick is fully engaged, because worn sticks often can't reach the edge.
+    ///
+    /// Must be larger than [`Self::inner_deadzone`], otherwise the stick is fully engaged as soon as it leaves the inner deadzone.
+    ///
+    /// Defaults to `0.95`.
+    pub outer_deadzone: f32,
+    /// How the position between the deadzones is mapped to the output.
+    ///
+    /// Defaults to [`ResponseCurve::Linear`].
+    pub curve: ResponseCurve,
+}
+
+impl StickSettings {
+    /// Set how the deadzones are measured.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - Radial or axial deadzones.
+    ///
+    /// # Returns
+    ///
+    /// - Updated settings.
+    #[inline]
+    #[must_use]
+    pub const fn with_shape(mut self, shape: DeadzoneShape) -> Self {
+        self.shape = shape;
+
+        self
+    }
+
+    /// Set the inner and outer deadzones.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Distance between `0.0` and `1.0` below which the stick is ignored.
+    /// * `outer` - Distance between `0.0` and `1.0` above which the stick is fully engaged, must be larger than `inner`.
+    ///
+    /// # Returns
+    ///
+    /// - Updated settings.
+    ///
+    /// # Panics
+    ///
+    /// - When `outer` is not larger than `inner`.
+    #[inline]
+    #[must_use]
+    pub fn with_deadzones(mut self, inner: f32, outer: f32) -> Self {
+        assert!(
+            outer > inner,
+            "Outer deadzone must be larger than the inner deadzone"
+        );
+
+        self.inner_deadzone = inner;
+        self.outer_deadzone = outer;
+
+        self
+    }
+
+    /// Set the response curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - How the position between the deadzones is mapped to the output.
+    ///
+    /// # Returns
+    ///
+    /// - Updated settings.
+    #[inline]
+    #[must_use]
+    pub const fn with_curve(mut self, curve: ResponseCurve) -> Self {
+        self.curve = curve;
+
+        self
+    }
+
+    /// Apply the deadzones and the curve to the raw position of a stick.
+    pub(crate) fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        match self.shape {
+            DeadzoneShape::Radial => {
+                // Ignore the stick when it's inside the inner deadzone
+                let length = x.hypot(y);
+                if length <= self.inner_deadzone {
+                    return (0.0, 0.0);
+                }
+
+                // Scale the length, keeping the direction
+                let scaled = self.scale(length);
+
+                (x / length * scaled, y / length * scaled)
+            }
+            DeadzoneShape::Axial => (self.apply_axis(x), self.apply_axis(y)),
+        }
+    }
+
+    /// Apply the deadzones and the curve to a single axis.
+    fn apply_axis(&self, value: f32) -> f32 {
+        // Ignore the axis when it's inside the inner deadzone
+        if value.abs() <= self.inner_deadzone {
+            return 0.0;
+        }
+
+        self.scale(value.abs()).copysign(value)
+    }
+
+    /// Map a distance between the deadzones to `0.0..=1.0` and apply the curve.
+    fn scale(&self, distance: f32) -> f32 {
+        // Prevent dividing by zero when the deadzones are set directly without validation
+        let range = (self.outer_deadzone - self.inner_deadzone).max(f32::EPSILON);
+        let normalized = ((distance - self.inner_deadzone) / range).clamp(0.0, 1.0);
+
+        self.curve.apply(normalized)
+    }
+}
+
+impl Default for StickSettings {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            shape: DeadzoneShape::Radial,
+            inner_deadzone: 0.15,
+            outer_deadzone: 0.95,
+            curve: ResponseCurve::Linear,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeadzoneShape, ResponseCurve, StickSettings};
+
+    #[test]
+    fn radial_deadzones() {
+        let settings = StickSettings::default().with_deadzones(0.2, 0.8);
+
+        assert_eq!(settings.apply(0.0, 0.0), (0.0, 0.0));
+        assert_eq!(settings.apply(0.1, 0.1), (0.0, 0.0));
+        assert_eq!(settings.apply(0.0, -0.2), (0.0, 0.0));
+
+        let (x, y) = settings.apply(0.8, 0.0);
+        assert!((x - 1.0).abs() < f32::EPSILON);
+        assert!(y.abs() < f32::EPSILON);
+
+        // Beyond the outer deadzone the direction is kept at full length
+        let (x, y) = settings.apply(-0.6, 0.8);
+        assert!((x.hypot(y) - 1.0).abs() < 1e-6);
+        assert!((x + 0.6).abs() < 1e-6);
+        assert!((y - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn axial_deadzones() {
+        let settings = StickSettings::default()
+            .with_shape(DeadzoneShape::Axial)
+            .with_deadzones(0.2, 0.8);
+
+        assert_eq!(settings.apply(0.0, 0.0), (0.0, 0.0));
+        assert_eq!(settings.apply(0.2, -0.1), (0.0, 0.0));
+
+        // Each axis is measured separately
+        let (x, y) = settings.apply(0.8, 0.1);
+        assert!((x - 1.0).abs() < f32::EPSILON);
+        assert!(y.abs() < f32::EPSILON);
+
+        let (x, y) = settings.apply(-1.0, 0.9);
+        assert!((x + 1.0).abs() < f32::EPSILON);
+        assert!((y - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn invalid_settings() {
+        // Deadzones set directly without validation
+        for outer_deadzone in [0.5, 0.3] {
+            let settings = StickSettings {
+                inner_deadzone: 0.5,
+                outer_deadzone,
+                ..StickSettings::default()
+            };
+
+            assert_eq!(settings.apply(0.4, 0.0), (0.0, 0.0));
+            let (x, y) = settings.apply(0.6, 0.0);
+            assert!((x - 1.0).abs() < f32::EPSILON);
+            assert!(y.abs() < f32::EPSILON);
+        }
+
+        // Exponents that aren't positive are clamped
+        for exponent in [0.0, -2.0] {
+            let settings = StickSettings::default()
+                .with_deadzones(0.2, 0.8)
+                .with_curve(ResponseCurve::Power(exponent));
+
+            assert_eq!(settings.apply(0.1, 0.0), (0.0, 0.0));
+            for value in [0.21, 0.5, 1.0] {
+                let (x, _) = settings.apply(value, 0.0);
+                assert!(x > 0.0 && x <= 1.0);
+            }
+        }
+    }
+}
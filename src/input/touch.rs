@@ -0,0 +1,40 @@
+//! State of fingers touching the screen.
+
+/// Single finger touching the screen.
+pub(crate) struct Touch {
+    /// Unique identifier of the finger, only valid for the duration of the touch.
+    pub(crate) id: u64,
+    /// Position on the buffer, `None` when outside of it.
+    pub(crate) position: Option<(f32, f32)>,
+    /// Whether the finger is still touching the screen.
+    pub(crate) is_down: bool,
+    /// Whether the touch started this update tick.
+    pub(crate) is_pressed: bool,
+    /// Whether the touch ended this update tick.
+    pub(crate) is_released: bool,
+}
+
+impl Touch {
+    /// Start a new touch.
+    pub(crate) const fn new(id: u64, position: Option<(f32, f32)>) -> Self {
+        Self {
+            id,
+            position,
+            is_down: true,
+            is_pressed: true,
+            is_released: false,
+        }
+    }
+
+    /// Lift the finger from the screen.
+    pub(crate) const fn release(&mut self) {
+        self.is_down = false;
+        self.is_released = true;
+    }
+
+    /// Handle the state changes using the update tick to respond to changes.
+    pub(crate) const fn update(&mut self) {
+        self.is_pressed = false;
+        self.is_released = false;
+    }
+}
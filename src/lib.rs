@@ -136,13 +136,19 @@
 //! [^escape-key]: [`Context::key_pressed`]
 
 pub mod assets;
+mod audio_backend;
 mod camera;
+mod clock;
 pub mod config;
 pub mod context;
 mod graphics;
-mod input;
+pub mod input;
 mod math;
+mod mixer;
+mod music;
 mod random;
+mod spatial;
+mod ui;
 
 pub use assets::source::AssetSource;
 /// Define the directory of the assets.
@@ -175,12 +181,12 @@ use web_time::Instant;
 use winit::{
     application::ApplicationHandler,
     dpi::{LogicalSize, PhysicalSize},
-    event::WindowEvent,
+    event::{DeviceEvent, DeviceId, WindowEvent},
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
     window::{WindowAttributes, WindowId},
 };
 /// Re-exported [`winit`](https://docs.rs/winit) type.
-pub use winit::{event::MouseButton, keyboard::KeyCode};
+pub use winit::{event::MouseButton, keyboard::KeyCode, window::CursorGrabMode};
 
 /// How fast old FPS values decay in the smoothed average.
 const FPS_SMOOTHED_AVERAGE_ALPHA: f32 = 0.8;
@@ -553,13 +559,44 @@ impl<G: Game> ApplicationHandler<Context> for State<G> {
                         ctx.input.update();
 
                         // Update camera targets
-                        ctx.main_camera.update_target();
-                        ctx.ui_camera.update_target();
+                        let (buffer_width, buffer_height) =
+                            (ctx.config.buffer_width, ctx.config.buffer_height);
+                        ctx.main_camera.update_target(
+                            ctx.config.update_delta_time,
+                            buffer_width,
+                            buffer_height,
+                        );
+                        ctx.ui_camera.update_target(
+                            ctx.config.update_delta_time,
+                            buffer_width,
+                            buffer_height,
+                        );
+                        for (_, camera) in &mut ctx.cameras {
+                            camera.update_target(
+                                ctx.config.update_delta_time,
+                                buffer_width,
+                                buffer_height,
+                            );
+                        }
+
+                        // Advance the audio when there's no audio device
+                        ctx.audio_manager.update(ctx.config.update_delta_time);
+
+                        // Follow the camera with sounds played at a world position
+                        ctx.update_emitters();
+
+                        // Detect the beats of the clocks
+                        for clock in ctx.clocks.values_mut() {
+                            clock.update();
+                        }
 
                         // Handle hot reloaded assets
                         #[cfg(not(target_arch = "wasm32"))]
                         assets::hot_reload::handle_changed_asset_files(ctx);
                     });
+
+                    // Continue the playlist when a song finished
+                    ctx.update_music_player();
                 }
 
                 ctx.write(|ctx| {
@@ -575,6 +612,12 @@ impl<G: Game> ApplicationHandler<Context> for State<G> {
                     // Update cameras
                     ctx.main_camera.update(frame_time, ctx.blending_factor);
                     ctx.ui_camera.update(frame_time, ctx.blending_factor);
+                    for (_, camera) in &mut ctx.cameras {
+                        camera.update(frame_time, ctx.blending_factor);
+                    }
+
+                    // Start a new user interface frame
+                    ctx.ui.begin_frame(&ctx.input);
                 });
 
                 // Only call render loop when the window is not minimized
@@ -583,6 +626,9 @@ impl<G: Game> ApplicationHandler<Context> for State<G> {
                 // Call the user render function with the context
                 if not_minimized {
                     self.game.render(ctx.clone());
+
+                    // Draw the cursor on top of everything
+                    ctx.draw_cursor_sprite();
                 }
 
                 ctx.write(|ctx| {
@@ -591,6 +637,9 @@ impl<G: Game> ApplicationHandler<Context> for State<G> {
                         ctx.graphics.render();
                     }
 
+                    // Finish the user interface frame
+                    ctx.ui.end_frame();
+
                     if ctx.exit {
                         // Tell winit that we want to exit
                         event_loop.exit();
@@ -617,7 +666,9 @@ impl<G: Game> ApplicationHandler<Context> for State<G> {
             WindowEvent::KeyboardInput { .. }
             | WindowEvent::CursorMoved { .. }
             | WindowEvent::MouseWheel { .. }
-            | WindowEvent::MouseInput { .. } => {
+            | WindowEvent::MouseInput { .. }
+            | WindowEvent::Touch(..)
+            | WindowEvent::Ime(..) => {
                 ctx.write(|ctx| ctx.input.handle_event(event, &ctx.graphics));
             }
             // Ignore the rest of the events
@@ -625,6 +676,23 @@ impl<G: Game> ApplicationHandler<Context> for State<G> {
         }
     }
 
+    fn device_event(
+        &mut self,
+        _event_loop: &ActiveEventLoop,
+        _device_id: DeviceId,
+        event: DeviceEvent,
+    ) {
+        // Do nothing if the window is not set up yet
+        let Some(ctx) = &mut self.ctx else {
+            return;
+        };
+
+        // Handle raw mouse movement with the input manager
+        if let DeviceEvent::MouseMotion { delta } = event {
+            ctx.write(|ctx| ctx.input.handle_mouse_motion(delta));
+        }
+    }
+
     fn user_event(&mut self, _event_loop: &ActiveEventLoop, ctx: Context) {
         // Call user passed init function
         self.game.init(ctx.clone());
@@ -0,0 +1,115 @@
+//! Named mixer tracks sounds can be routed through.
+
+use std::time::Duration;
+
+use kira::{
+    Decibels, Mix, Tween,
+    effect::{
+        delay::{DelayBuilder, DelayHandle},
+        filter::{FilterBuilder, FilterHandle, FilterMode},
+        reverb::{ReverbBuilder, ReverbHandle},
+    },
+    track::{TrackBuilder, TrackHandle},
+};
+
+use crate::audio_backend::AudioBackend;
+
+/// Delay between the echoes of the delay effect.
+const DELAY_TIME: Duration = Duration::from_millis(250);
+
+/// Mixer sub-track with a fixed chain of effects that are disabled by default.
+pub(crate) struct MixerTrack {
+    /// Sub-track on the audio manager.
+    pub(crate) handle: TrackHandle,
+    /// Filter effect, first in the chain.
+    filter: FilterHandle,
+    /// Reverb effect, second in the chain.
+    reverb: ReverbHandle,
+    /// Delay effect, last in the chain.
+    delay: DelayHandle,
+    /// Volume when not muted.
+    volume: Decibels,
+    /// Whether the track is silenced.
+    muted: bool,
+}
+
+impl MixerTrack {
+    /// Create a new track on the audio manager.
+    ///
+    /// # Panics
+    ///
+    /// - When the maximum amount of tracks is reached.
+    pub(crate) fn new(audio_manager: &mut AudioBackend) -> Self {
+        // Add all effects without affecting the sound yet
+        let mut builder = TrackBuilder::new();
+        let filter = builder.add_effect(
+            FilterBuilder::new()
+                .mode(FilterMode::LowPass)
+                .cutoff(20_000.0)
+                .mix(Mix::DRY),
+        );
+        let reverb = builder.add_effect(ReverbBuilder::new().mix(Mix::DRY));
+        let delay = builder.add_effect(DelayBuilder::new().delay_time(DELAY_TIME).mix(Mix::DRY));
+
+        let handle = audio_manager
+            .add_sub_track(builder)
+            .expect("Error creating mixer track");
+
+        Self {
+            handle,
+            filter,
+            reverb,
+            delay,
+            volume: Decibels::IDENTITY,
+            muted: false,
+        }
+    }
+
+    /// Change the volume, only audible when not muted.
+    pub(crate) fn set_volume(&mut self, volume: Decibels, tween: Tween) {
+        self.volume = volume;
+
+        if !self.muted {
+            self.handle.set_volume(volume, tween);
+        }
+    }
+
+    /// Silence the track or restore its volume.
+    pub(crate) fn set_muted(&mut self, muted: bool, tween: Tween) {
+        self.muted = muted;
+
+        let volume = if muted {
+            Decibels::SILENCE
+        } else {
+            self.volume
+        };
+        self.handle.set_volume(volume, tween);
+    }
+
+    /// Whether the track is silenced.
+    pub(crate) const fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    /// Enable the filter.
+    pub(crate) fn set_filter(&mut self, mode: FilterMode, cutoff: f64, tween: Tween) {
+        self.filter.set_mode(mode);
+        self.filter.set_cutoff(cutoff, tween);
+        self.filter.set_mix(Mix::WET, tween);
+    }
+
+    /// Disable the filter.
+    pub(crate) fn remove_filter(&mut self, tween: Tween) {
+        self.filter.set_mix(Mix::DRY, tween);
+    }
+
+    /// Change how much of the reverb is mixed in.
+    pub(crate) fn set_reverb(&mut self, mix: Mix, tween: Tween) {
+        self.reverb.set_mix(mix, tween);
+    }
+
+    /// Change how much of the delay is mixed in.
+    pub(crate) fn set_delay(&mut self, mix: Mix, tween: Tween) {
+        self.delay.set_mix(mix, tween);
+    }
+}
@@ -0,0 +1,139 @@
+//! State of the music player crossfading between songs.
+
+use hashbrown::HashMap;
+
+use crate::context::audio::SoundHandle;
+
+/// Name of the mixer track all music is played on.
+pub(crate) const MUSIC_TRACK: &str = "music";
+
+/// Music player owning at most one song that's playing.
+#[derive(Default)]
+pub(crate) struct MusicPlayer {
+    /// Asset path and handle of the song that's playing.
+    pub(crate) current: Option<(String, SoundHandle)>,
+    /// Seconds into each song when it was switched away from, so it can be resumed.
+    pub(crate) positions: HashMap<String, f32>,
+    /// Asset paths of the songs in the playlist, in the order they are played.
+    playlist: Vec<String>,
+    /// Index of the song in the playlist that's playing.
+    playlist_index: usize,
+    /// Whether the playlist is reshuffled every time it starts over.
+    shuffle: bool,
+    /// Whether the next song of the playlist is played when the current one finishes.
+    pub(crate) playlist_active: bool,
+}
+
+impl MusicPlayer {
+    /// Replace the playlist and start at its first song.
+    pub(crate) fn set_playlist(&mut self, playlist: Vec<String>, shuffle: bool) {
+        self.playlist = playlist;
+        self.playlist_index = 0;
+        self.shuffle = shuffle;
+
+        if shuffle {
+            fastrand::shuffle(&mut self.playlist);
+        }
+    }
+
+    /// Asset path of the song in the playlist that's playing.
+    pub(crate) fn playlist_song(&self) -> Option<&str> {
+        self.playlist.get(self.playlist_index).map(String::as_str)
+    }
+
+    /// Move to the next song in the playlist, starting over at the end.
+    pub(crate) fn advance_playlist(&mut self) -> Option<&str> {
+        if self.playlist.is_empty() {
+            return None;
+        }
+
+        self.playlist_index += 1;
+        if self.playlist_index >= self.playlist.len() {
+            self.playlist_index = 0;
+
+            if self.shuffle {
+                // Don't play the last song twice in a row
+                let last = self.playlist.last().cloned();
+                fastrand::shuffle(&mut self.playlist);
+                if self.playlist.len() > 1 && self.playlist.first() == last.as_ref() {
+                    self.playlist.swap(0, 1);
+                }
+            }
+        }
+
+        self.playlist_song()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MusicPlayer;
+
+    /// Create a playlist with numbered songs.
+    fn playlist(count: usize, shuffle: bool) -> MusicPlayer {
+        let mut player = MusicPlayer::default();
+        player.set_playlist((0..count).map(|index| index.to_string()).collect(), shuffle);
+
+        player
+    }
+
+    #[test]
+    fn advance_without_shuffle() {
+        let mut player = playlist(3, false);
+
+        assert_eq!(player.playlist_song(), Some("0"));
+        assert_eq!(player.advance_playlist(), Some("1"));
+        assert_eq!(player.advance_playlist(), Some("2"));
+        assert_eq!(player.advance_playlist(), Some("0"));
+        assert_eq!(player.advance_playlist(), Some("1"));
+    }
+
+    #[test]
+    fn advance_empty() {
+        let mut player = playlist(0, true);
+
+        assert_eq!(player.playlist_song(), None);
+        assert_eq!(player.advance_playlist(), None);
+    }
+
+    #[test]
+    fn shuffle_single_song() {
+        let mut player = playlist(1, true);
+
+        assert_eq!(player.playlist_song(), Some("0"));
+        for _ in 0..10 {
+            assert_eq!(player.advance_playlist(), Some("0"));
+        }
+    }
+
+    #[test]
+    fn shuffle_without_repeats() {
+        for count in [2, 3, 5] {
+            let mut player = playlist(count, true);
+
+            let first = player.playlist_song().map(str::to_owned);
+            let songs = std::iter::once(first)
+                .chain(
+                    std::iter::repeat_with(|| player.advance_playlist().map(str::to_owned))
+                        .take(count * 100 - 1),
+                )
+                .collect::<Vec<_>>();
+
+            // The same song is never played twice in a row
+            assert!(
+                songs
+                    .iter()
+                    .zip(&songs[1..])
+                    .all(|(song, next)| song != next)
+            );
+
+            // Every song is played once before starting over
+            for cycle in songs.chunks(count) {
+                let mut cycle = cycle.to_vec();
+                cycle.sort();
+                cycle.dedup();
+                assert_eq!(cycle.len(), count);
+            }
+        }
+    }
+}
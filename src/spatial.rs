@@ -0,0 +1,202 @@
+//! Panning and volume falloff of sounds positioned in the world.
+
+/// How the volume of a positioned sound decreases with the distance to the center of the camera.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[non_exhaustive]
+pub enum Falloff {
+    /// Volume decreases evenly until it's silent at the maximum distance.
+    #[default]
+    Linear,
+    /// Volume decreases quickly close to the camera and slowly further away, which sounds more natural.
+    Quadratic,
+    /// Volume decreases with a custom exponent, values below `1.0` keep sounds loud for longer.
+    ///
+    /// The exponent must be larger than `0.0`, smaller values are clamped to a tiny positive exponent so sounds are still silent at the maximum distance.
+    Power(f32),
+}
+
+impl Falloff {
+    /// Map a distance between `0.0` and `1.0` to a volume factor.
+    fn apply(self, distance: f32) -> f32 {
+        let remaining = 1.0 - distance;
+
+        match self {
+            Self::Linear => remaining,
+            Self::Quadratic => remaining * remaining,
+            Self::Power(exponent) => remaining.powf(exponent.max(f32::EPSILON)),
+        }
+    }
+}
+
+/// Settings for sounds played at a world position with [`crate::context::audio::AudioContext::at`].
+///
+/// Set with [`crate::Context::set_spatial_audio_settings`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[allow(clippy::exhaustive_structs)]
+pub struct SpatialSettings {
+    /// How the volume decreases with the distance.
+    ///
+    /// Defaults to [`Falloff::Linear`].
+    pub falloff: Falloff,
+    /// Distance in pixels from the center of the camera at which sounds are silent.
+    ///
+    /// Defaults to `None`, which is the width of the buffer.
+    pub max_distance: Option<f32>,
+}
+
+impl SpatialSettings {
+    /// Set how the volume decreases with the distance.
+    ///
+    /// # Arguments
+    ///
+    /// * `falloff` - Curve of the volume between the center of the camera and the maximum distance.
+    ///
+    /// # Returns
+    ///
+    /// - Updated settings.
+    #[inline]
+    #[must_use]
+    pub const fn with_falloff(mut self, falloff: Falloff) -> Self {
+        self.falloff = falloff;
+
+        self
+    }
+
+    /// Set the distance at which sounds are silent.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_distance` - Distance in pixels from the center of the camera, must be larger than `0.0`.
+    ///
+    /// # Returns
+    ///
+    /// - Updated settings.
+    ///
+    /// # Panics
+    ///
+    /// - When `max_distance` is not larger than `0.0`.
+    #[inline]
+    #[must_use]
+    pub fn with_max_distance(mut self, max_distance: f32) -> Self {
+        assert!(max_distance > 0.0, "Maximum distance must be positive");
+
+        self.max_distance = Some(max_distance);
+
+        self
+    }
+
+    /// Calculate the volume factor and the panning of a sound.
+    ///
+    /// Panning is in the range `0.0..=1.0`, where `0.0` is hard left.
+    pub(crate) fn apply(&self, offset_x: f32, offset_y: f32, buffer_width: f32) -> (f32, f32) {
+        // Sounds at the horizontal edges of the buffer are panned fully
+        let half_width = buffer_width / 2.0;
+        let panning = (offset_x / half_width).clamp(-1.0, 1.0).mul_add(0.5, 0.5);
+
+        // Sounds are silent at the maximum distance
+        let max_distance = self.max_distance.unwrap_or(buffer_width);
+        let distance = (offset_x.hypot(offset_y) / max_distance).clamp(0.0, 1.0);
+
+        (self.falloff.apply(distance), panning)
+    }
+}
+
+/// Sound played at a world position, updated every update tick.
+pub(crate) struct Emitter {
+    /// Horizontal position in the world.
+    pub(crate) x: f32,
+    /// Vertical position in the world.
+    pub(crate) y: f32,
+    /// Volume of the sound before the falloff is applied.
+    pub(crate) volume: f32,
+    /// Volume factor from the falloff that's last applied to the sound.
+    pub(crate) gain: f32,
+    /// Panning that's last applied to the sound.
+    pub(crate) panning: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Falloff, SpatialSettings};
+
+    /// All falloff curves to test.
+    const FALLOFFS: [Falloff; 3] = [Falloff::Linear, Falloff::Quadratic, Falloff::Power(0.5)];
+
+    #[test]
+    fn center() {
+        for falloff in FALLOFFS {
+            let (gain, panning) = SpatialSettings::default()
+                .with_falloff(falloff)
+                .apply(0.0, 0.0, 320.0);
+
+            assert!((gain - 1.0).abs() < f32::EPSILON);
+            assert!((panning - 0.5).abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn buffer_edges() {
+        for falloff in FALLOFFS {
+            let settings = SpatialSettings::default().with_falloff(falloff);
+
+            let (left_gain, left_panning) = settings.apply(-160.0, 0.0, 320.0);
+            assert!(left_panning.abs() < f32::EPSILON);
+
+            let (right_gain, right_panning) = settings.apply(160.0, 0.0, 320.0);
+            assert!((right_panning - 1.0).abs() < f32::EPSILON);
+
+            // Halfway to the default maximum distance of the buffer width
+            assert!(left_gain > 0.0 && left_gain < 1.0);
+            assert!((left_gain - right_gain).abs() < f32::EPSILON);
+
+            // Panning is clamped beyond the edges
+            let (_, panning) = settings.apply(-1000.0, 0.0, 320.0);
+            assert!(panning.abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn beyond_max_distance() {
+        for falloff in FALLOFFS {
+            let settings = SpatialSettings::default()
+                .with_falloff(falloff)
+                .with_max_distance(100.0);
+
+            assert!(settings.apply(0.0, 100.0, 320.0).0.abs() < f32::EPSILON);
+            assert!(settings.apply(80.0, 80.0, 320.0).0.abs() < f32::EPSILON);
+            assert!(settings.apply(0.0, -1000.0, 320.0).0.abs() < f32::EPSILON);
+
+            // Without a maximum distance the width of the buffer is used
+            let default_settings = SpatialSettings::default().with_falloff(falloff);
+            assert!(default_settings.apply(0.0, 320.0, 320.0).0.abs() < f32::EPSILON);
+            assert!(default_settings.apply(0.0, 319.0, 320.0).0 > 0.0);
+        }
+    }
+
+    #[test]
+    fn falloff_curves() {
+        let gain = |falloff| {
+            SpatialSettings::default()
+                .with_falloff(falloff)
+                .with_max_distance(100.0)
+                .apply(50.0, 0.0, 320.0)
+                .0
+        };
+
+        assert!((gain(Falloff::Linear) - 0.5).abs() < f32::EPSILON);
+        assert!((gain(Falloff::Quadratic) - 0.25).abs() < f32::EPSILON);
+        assert!((gain(Falloff::Power(0.5)) - 0.5_f32.sqrt()).abs() < f32::EPSILON);
+
+        // Exponents that aren't positive are clamped
+        for exponent in [0.0, -1.0] {
+            let settings = SpatialSettings::default()
+                .with_falloff(Falloff::Power(exponent))
+                .with_max_distance(100.0);
+
+            let (gain, _) = settings.apply(50.0, 0.0, 320.0);
+            assert!(gain > 0.0 && gain <= 1.0);
+            assert!(settings.apply(100.0, 0.0, 320.0).0.abs() < f32::EPSILON);
+            assert!(settings.apply(500.0, 0.0, 320.0).0.abs() < f32::EPSILON);
+        }
+    }
+}
@@ -0,0 +1,418 @@
+//! State for the immediate-mode user interface.
+
+use std::rc::Rc;
+
+use gilrs::{Axis, Button};
+use winit::{event::MouseButton, keyboard::KeyCode};
+
+use crate::{
+    context::ui::{UiNavigation, UiStyle},
+    input::Input,
+};
+
+/// Direction in which a layout places its widgets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    /// Place widgets below each other.
+    Vertical,
+    /// Place widgets next to each other.
+    Horizontal,
+}
+
+/// Area in which widgets are placed one after another.
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    /// Direction in which widgets are placed.
+    direction: Direction,
+    /// Horizontal position of the top left corner.
+    x: f32,
+    /// Vertical position of the top left corner.
+    y: f32,
+    /// Horizontal position where the next widget will be placed.
+    cursor_x: f32,
+    /// Vertical position where the next widget will be placed.
+    cursor_y: f32,
+    /// Width of all widgets combined.
+    width: f32,
+    /// Height of all widgets combined.
+    height: f32,
+}
+
+impl Layout {
+    /// Create an empty layout at a position.
+    const fn new(direction: Direction, x: f32, y: f32) -> Self {
+        Self {
+            direction,
+            x,
+            y,
+            cursor_x: x,
+            cursor_y: y,
+            width: 0.0,
+            height: 0.0,
+        }
+    }
+
+    /// Reserve space for a widget and get its position.
+    fn allocate(&mut self, width: f32, height: f32, spacing: f32) -> (f32, f32) {
+        let position = (self.cursor_x, self.cursor_y);
+
+        // Move the cursor past the widget
+        match self.direction {
+            Direction::Vertical => self.cursor_y += height + spacing,
+            Direction::Horizontal => self.cursor_x += width + spacing,
+        }
+
+        // Grow the size of the layout
+        self.width = self.width.max(position.0 + width - self.x);
+        self.height = self.height.max(position.1 + height - self.y);
+
+        position
+    }
+}
+
+/// Result of interacting with a widget.
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub(crate) struct Interaction {
+    /// Mouse is on top of the widget.
+    pub(crate) hovered: bool,
+    /// Mouse has been pressed on the widget and is still held.
+    pub(crate) held: bool,
+    /// Mouse has been pressed and released on the widget, or the confirm action is pressed while it's focused, this frame.
+    pub(crate) clicked: bool,
+    /// Widget has the focus for keyboard and gamepad navigation.
+    pub(crate) focused: bool,
+}
+
+/// Widget that can receive the focus.
+#[derive(Debug, Clone, Copy)]
+struct Focusable {
+    /// Identifier of the widget.
+    id: u32,
+    /// Horizontal center of the widget.
+    center_x: f32,
+    /// Vertical center of the widget.
+    center_y: f32,
+    /// Whether horizontal navigation changes the value of the widget instead of moving the focus.
+    captures_horizontal: bool,
+}
+
+/// Held state of all navigation actions.
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct Actions {
+    /// Move the focus up.
+    up: bool,
+    /// Move the focus down.
+    down: bool,
+    /// Move the focus left.
+    left: bool,
+    /// Move the focus right.
+    right: bool,
+    /// Click the focused widget.
+    confirm: bool,
+    /// Leave the menu.
+    cancel: bool,
+}
+
+impl Actions {
+    /// Sample the actions from the keyboard and all connected gamepads.
+    fn sample(input: &Input, navigation: &UiNavigation) -> Self {
+        // Arrow keys
+        let mut actions = Self {
+            up: input.key_held(KeyCode::ArrowUp),
+            down: input.key_held(KeyCode::ArrowDown),
+            left: input.key_held(KeyCode::ArrowLeft),
+            right: input.key_held(KeyCode::ArrowRight),
+            confirm: navigation
+                .confirm_keys
+                .iter()
+                .any(|key| input.key_held(*key)),
+            cancel: navigation
+                .cancel_keys
+                .iter()
+                .any(|key| input.key_held(*key)),
+        };
+
+        for gamepad_id in input.gamepads_ids() {
+            let button_held = |button| input.gamepad_button_held(gamepad_id, button) == Some(true);
+            let axis = |axis| input.gamepad_axis(gamepad_id, axis).unwrap_or_default();
+
+            // Directional pad
+            actions.up |= button_held(Button::DPadUp);
+            actions.down |= button_held(Button::DPadDown);
+            actions.left |= button_held(Button::DPadLeft);
+            actions.right |= button_held(Button::DPadRight);
+
+            // Left stick, the vertical axis points up
+            let (stick_x, stick_y) = (axis(Axis::LeftStickX), axis(Axis::LeftStickY));
+            actions.up |= stick_y > navigation.stick_threshold;
+            actions.down |= stick_y < -navigation.stick_threshold;
+            actions.left |= stick_x < -navigation.stick_threshold;
+            actions.right |= stick_x > navigation.stick_threshold;
+
+            // Mapped buttons
+            actions.confirm |= navigation.confirm_buttons.iter().any(|b| button_held(*b));
+            actions.cancel |= navigation.cancel_buttons.iter().any(|b| button_held(*b));
+        }
+
+        actions
+    }
+}
+
+/// Immediate-mode user interface state persisting between frames.
+pub(crate) struct Ui {
+    /// How to draw the widgets.
+    pub(crate) style: Option<Rc<UiStyle>>,
+    /// Stack of nested layouts, the first is the root layout.
+    layouts: Vec<Layout>,
+    /// Identifier of the next widget, widgets are identified by the order in which they are created.
+    next_id: u32,
+    /// Widget on which the mouse has been pressed.
+    active: Option<u32>,
+    /// Whether the left mouse button is held this frame.
+    mouse_held: bool,
+    /// Whether the left mouse button was held the previous frame.
+    mouse_was_held: bool,
+    /// Which keys and buttons navigate the widgets.
+    pub(crate) navigation: UiNavigation,
+    /// Widget with the focus for keyboard and gamepad navigation.
+    pub(crate) focused: Option<u32>,
+    /// Widgets that can receive the focus created this frame.
+    focusables: Vec<Focusable>,
+    /// Widgets that can receive the focus created the previous frame, used for navigating.
+    previous_focusables: Vec<Focusable>,
+    /// Navigation actions held this frame.
+    actions: Actions,
+    /// Navigation actions held the previous frame.
+    previous_actions: Actions,
+}
+
+impl Ui {
+    /// Setup the state without a style.
+    pub(crate) fn new() -> Self {
+        Self {
+            style: None,
+            layouts: vec![Layout::new(Direction::Vertical, 0.0, 0.0)],
+            next_id: 0,
+            active: None,
+            mouse_held: false,
+            mouse_was_held: false,
+            navigation: UiNavigation::default(),
+            focused: None,
+            focusables: Vec::new(),
+            previous_focusables: Vec::new(),
+            actions: Actions::default(),
+            previous_actions: Actions::default(),
+        }
+    }
+
+    /// Start a new frame, must be called before rendering.
+    ///
+    /// The input state is sampled every frame instead of every update tick so clicks and presses aren't missed between update ticks.
+    pub(crate) fn begin_frame(&mut self, input: &Input) {
+        self.mouse_held = input.mouse_held(MouseButton::Left);
+        self.actions = Actions::sample(input, &self.navigation);
+
+        // Navigate between the widgets of the previous frame
+        std::mem::swap(&mut self.focusables, &mut self.previous_focusables);
+        self.focusables.clear();
+        self.navigate();
+
+        // Reset the layouts
+        self.layouts.truncate(1);
+        self.layouts[0] = Layout::new(Direction::Vertical, 0.0, 0.0);
+
+        self.next_id = 0;
+    }
+
+    /// Finish the frame, must be called after rendering.
+    pub(crate) const fn end_frame(&mut self) {
+        self.mouse_was_held = self.mouse_held;
+        self.previous_actions = self.actions;
+
+        // Nothing can be active when the mouse isn't held anymore
+        if !self.mouse_held {
+            self.active = None;
+        }
+    }
+
+    /// Move the cursor of the current layout.
+    pub(crate) fn set_position(&mut self, x: f32, y: f32) {
+        let layout = self.layout_mut();
+        layout.cursor_x = x;
+        layout.cursor_y = y;
+    }
+
+    /// Start a nested layout at the cursor of the current layout.
+    pub(crate) fn push_layout(&mut self, direction: Direction) {
+        let layout = self.layout_mut();
+        let (x, y) = (layout.cursor_x, layout.cursor_y);
+
+        self.layouts.push(Layout::new(direction, x, y));
+    }
+
+    /// Finish a nested layout, reserving its size in the parent layout.
+    pub(crate) fn pop_layout(&mut self, spacing: f32) {
+        // The root layout can't be removed
+        if self.layouts.len() <= 1 {
+            return;
+        }
+
+        let layout = self.layouts.pop().expect("Layout stack is empty");
+        self.layout_mut()
+            .allocate(layout.width, layout.height, spacing);
+    }
+
+    /// Reserve space for a new widget, returning its identifier and position.
+    pub(crate) fn allocate(&mut self, width: f32, height: f32, spacing: f32) -> (u32, f32, f32) {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let (x, y) = self.layout_mut().allocate(width, height, spacing);
+
+        (id, x, y)
+    }
+
+    /// Handle the mouse and the focus for a widget.
+    ///
+    /// When `captures_horizontal` is set horizontal navigation doesn't move the focus away from the widget, use [`Self::horizontal_step`] instead.
+    pub(crate) fn interact(
+        &mut self,
+        id: u32,
+        (x, y, width, height): (f32, f32, f32, f32),
+        mouse: Option<(f32, f32)>,
+        captures_horizontal: bool,
+    ) -> Interaction {
+        // Register the widget so it can be navigated to next frame
+        self.focusables.push(Focusable {
+            id,
+            center_x: width.mul_add(0.5, x),
+            center_y: height.mul_add(0.5, y),
+            captures_horizontal,
+        });
+
+        let hovered = mouse.is_some_and(|(mouse_x, mouse_y)| {
+            mouse_x >= x && mouse_y >= y && mouse_x < x + width && mouse_y < y + height
+        });
+
+        // Start interacting and move the focus when the mouse is pressed on top of the widget
+        if hovered && self.mouse_pressed() {
+            self.active = Some(id);
+            self.focused = Some(id);
+        }
+
+        let is_active = self.active == Some(id);
+        let focused = self.focused == Some(id);
+
+        Interaction {
+            hovered,
+            held: is_active && self.mouse_held,
+            clicked: (is_active && hovered && self.mouse_released())
+                || (focused && self.action_pressed(|actions| actions.confirm)),
+            focused,
+        }
+    }
+
+    /// Which direction a focused widget capturing horizontal navigation is moved this frame.
+    ///
+    /// Returns `-1.0` for left, `1.0` for right and `0.0` otherwise.
+    pub(crate) fn horizontal_step(&self, id: u32) -> f32 {
+        if self.focused != Some(id) {
+            return 0.0;
+        }
+
+        if self.action_pressed(|actions| actions.left) {
+            -1.0
+        } else if self.action_pressed(|actions| actions.right) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Whether the cancel action is pressed this frame.
+    pub(crate) fn cancelled(&self) -> bool {
+        self.action_pressed(|actions| actions.cancel)
+    }
+
+    /// Move the focus when a direction is pressed.
+    fn navigate(&mut self) {
+        // Get the pressed direction
+        let (direction_x, direction_y) = if self.action_pressed(|actions| actions.up) {
+            (0.0, -1.0)
+        } else if self.action_pressed(|actions| actions.down) {
+            (0.0, 1.0)
+        } else if self.action_pressed(|actions| actions.left) {
+            (-1.0, 0.0)
+        } else if self.action_pressed(|actions| actions.right) {
+            (1.0, 0.0)
+        } else {
+            return;
+        };
+
+        // Focus the first widget when nothing, or a widget that doesn't exist anymore, is focused
+        let Some(current) = self.focused.and_then(|focused| {
+            self.previous_focusables
+                .iter()
+                .find(|focusable| focusable.id == focused)
+        }) else {
+            self.focused = self
+                .previous_focusables
+                .first()
+                .map(|focusable| focusable.id);
+            return;
+        };
+
+        // The widget handles horizontal navigation itself
+        if current.captures_horizontal && direction_x != 0.0 {
+            return;
+        }
+
+        // Find the closest widget in the direction, widgets not aligned with the direction are further away
+        let next = self
+            .previous_focusables
+            .iter()
+            .filter_map(|focusable| {
+                let (delta_x, delta_y) = (
+                    focusable.center_x - current.center_x,
+                    focusable.center_y - current.center_y,
+                );
+                let distance = delta_x.mul_add(direction_x, delta_y * direction_y);
+                if distance <= 0.0 {
+                    return None;
+                }
+
+                let misalignment = delta_x.mul_add(direction_y, delta_y * direction_x).abs();
+
+                Some((misalignment.mul_add(2.0, distance), focusable.id))
+            })
+            .min_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        if let Some((_, id)) = next {
+            self.focused = Some(id);
+        }
+    }
+
+    /// Whether a navigation action goes from "not held" to "held" this frame.
+    fn action_pressed(&self, action: impl Fn(&Actions) -> bool) -> bool {
+        action(&self.actions) && !action(&self.previous_actions)
+    }
+
+    /// Whether the mouse is pressed this frame.
+    const fn mouse_pressed(&self) -> bool {
+        self.mouse_held && !self.mouse_was_held
+    }
+
+    /// Whether the mouse is released this frame.
+    const fn mouse_released(&self) -> bool {
+        !self.mouse_held && self.mouse_was_held
+    }
+
+    /// Get the current layout.
+    fn layout_mut(&mut self) -> &mut Layout {
+        self.layouts
+            .last_mut()
+            .expect("Root layout is never removed")
+    }
+}